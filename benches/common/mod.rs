@@ -0,0 +1,132 @@
+//! A minimal benchmark harness, so benchmarks run offline without extra dependencies.
+//!
+//! Each benchmark is warmed up, then timed over several samples. The median time per
+//! iteration is reported, which is less sensitive to outliers than the mean.
+
+#![allow(dead_code)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const WARMUP: Duration = Duration::from_millis(100);
+const SAMPLES: usize = 20;
+const SAMPLE_TIME: Duration = Duration::from_millis(20);
+
+/// Time `f` and return the median duration of a single call in nanoseconds.
+pub fn bench<T>(mut f: impl FnMut() -> T) -> f64 {
+    // Warm up and estimate how many iterations fit into one sample
+    let start = Instant::now();
+    let mut iters: u64 = 0;
+    while start.elapsed() < WARMUP {
+        black_box(f());
+        iters += 1;
+    }
+    let per_sample = (iters * SAMPLE_TIME.as_nanos() as u64 / WARMUP.as_nanos() as u64).max(1);
+
+    let mut samples: Vec<f64> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..per_sample {
+                black_box(f());
+            }
+            start.elapsed().as_secs_f64() * 1e9 / per_sample as f64
+        })
+        .collect();
+
+    samples.sort_by(f64::total_cmp);
+    samples[SAMPLES / 2]
+}
+
+/// Like [`bench`], but creates a fresh input with `setup` for every call and only times `f`.
+///
+/// Useful for methods that consume their input.
+pub fn bench_batched<I, T>(mut setup: impl FnMut() -> I, mut f: impl FnMut(I) -> T) -> f64 {
+    let mut time = |iters: u64| {
+        let mut elapsed = Duration::ZERO;
+        for _ in 0..iters {
+            let input = setup();
+            let start = Instant::now();
+            black_box(f(black_box(input)));
+            elapsed += start.elapsed();
+        }
+        elapsed
+    };
+
+    // Warm up and estimate how many iterations fit into one sample
+    let mut iters: u64 = 0;
+    let mut warmup = Duration::ZERO;
+    while warmup < WARMUP {
+        warmup += time(1);
+        iters += 1;
+    }
+    let per_sample = (iters * SAMPLE_TIME.as_nanos() as u64 / WARMUP.as_nanos() as u64).max(1);
+
+    let mut samples: Vec<f64> = (0..SAMPLES)
+        .map(|_| time(per_sample).as_secs_f64() * 1e9 / per_sample as f64)
+        .collect();
+
+    samples.sort_by(f64::total_cmp);
+    samples[SAMPLES / 2]
+}
+
+/// Collects benchmark results and prints them as a markdown table.
+pub struct Table {
+    title: String,
+    columns: Vec<&'static str>,
+    rows: Vec<(String, Vec<Option<f64>>)>,
+}
+
+impl Table {
+    pub fn new(title: impl Into<String>, columns: &[&'static str]) -> Self {
+        Self {
+            title: title.into(),
+            columns: columns.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Add a row of nanosecond timings, with `None` for columns that don't apply.
+    pub fn row(&mut self, name: impl Into<String>, results: Vec<Option<f64>>) {
+        assert_eq!(results.len(), self.columns.len());
+        let name = name.into();
+        eprintln!("{}: {name} done", self.title);
+        self.rows.push((name, results));
+    }
+
+    pub fn print(&self) {
+        println!("\n### {}\n", self.title);
+        println!("| benchmark | {} |", self.columns.join(" | "));
+        println!("|---|{}", "---:|".repeat(self.columns.len()));
+        for (name, results) in &self.rows {
+            let cells: Vec<_> = results
+                .iter()
+                .map(|r| r.map(format_ns).unwrap_or_else(|| "-".to_string()))
+                .collect();
+            println!("| {name} | {} |", cells.join(" | "));
+        }
+    }
+}
+
+fn format_ns(ns: f64) -> String {
+    if ns < 1_000.0 {
+        format!("{ns:.2} ns")
+    } else if ns < 1_000_000.0 {
+        format!("{:.2} µs", ns / 1e3)
+    } else {
+        format!("{:.2} ms", ns / 1e6)
+    }
+}
+
+/// A deterministic pseudo-random sequence of variant indices, so branch prediction can't
+/// learn the pattern and every run uses the same input.
+pub fn variant_indices(variants: usize, len: usize) -> Vec<usize> {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % variants as u64) as usize
+        })
+        .collect()
+}
@@ -0,0 +1,365 @@
+//! Compares methods generated by `disponent::declare!` with a hand-written `match`,
+//! `enum_dispatch` and `Box<dyn Trait>` for each kind of method `disponent` supports.
+//!
+//! Run with `cargo bench --bench comparison`. Results are printed as a markdown table that can
+//! be kept alongside release notes to track changes over time. Methods an approach can't
+//! dispatch are shown as `-`.
+
+mod common;
+
+use std::hint::black_box;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+/// Number of values each benchmark iterates over.
+const LEN: usize = 1024;
+
+/// The inner type of every variant, `N` makes each variant a distinct type.
+#[derive(Clone)]
+pub struct V<const N: u64>(u64);
+
+mod with_disponent {
+    use super::V;
+
+    disponent::declare!(
+        #[derive(Clone)]
+        pub enum Backends {
+            A(V<1>),
+            B(V<3>),
+            C(V<5>),
+            D(V<7>),
+        }
+
+        pub trait Backend {
+            fn get(&self, x: u64) -> u64;
+            fn bump(&mut self, x: u64);
+            fn into_value(self) -> u64;
+            fn mix<T: Into<u64>>(&self, x: T) -> u64;
+            fn fetch(&self, x: u64) -> impl Future<Output = u64>;
+        }
+    );
+
+    impl<const N: u64> Backend for V<N> {
+        #[inline]
+        fn get(&self, x: u64) -> u64 {
+            x.wrapping_mul(N) ^ self.0
+        }
+        #[inline]
+        fn bump(&mut self, x: u64) {
+            self.0 = self.0.wrapping_add(x.wrapping_mul(N));
+        }
+        #[inline]
+        fn into_value(self) -> u64 {
+            self.0.wrapping_mul(N)
+        }
+        #[inline]
+        fn mix<T: Into<u64>>(&self, x: T) -> u64 {
+            x.into().rotate_left(N as u32) ^ self.0
+        }
+        #[inline]
+        async fn fetch(&self, x: u64) -> u64 {
+            x.wrapping_add(N) ^ self.0
+        }
+    }
+}
+
+mod handwritten {
+    use super::V;
+    use super::with_disponent::Backend;
+
+    #[derive(Clone)]
+    pub enum Backends {
+        A(V<1>),
+        B(V<3>),
+        C(V<5>),
+        D(V<7>),
+    }
+
+    impl Backends {
+        pub fn get(&self, x: u64) -> u64 {
+            match self {
+                Backends::A(inner) => inner.get(x),
+                Backends::B(inner) => inner.get(x),
+                Backends::C(inner) => inner.get(x),
+                Backends::D(inner) => inner.get(x),
+            }
+        }
+        pub fn bump(&mut self, x: u64) {
+            match self {
+                Backends::A(inner) => inner.bump(x),
+                Backends::B(inner) => inner.bump(x),
+                Backends::C(inner) => inner.bump(x),
+                Backends::D(inner) => inner.bump(x),
+            }
+        }
+        pub fn into_value(self) -> u64 {
+            match self {
+                Backends::A(inner) => inner.into_value(),
+                Backends::B(inner) => inner.into_value(),
+                Backends::C(inner) => inner.into_value(),
+                Backends::D(inner) => inner.into_value(),
+            }
+        }
+        pub fn mix<T: Into<u64>>(&self, x: T) -> u64 {
+            match self {
+                Backends::A(inner) => inner.mix(x),
+                Backends::B(inner) => inner.mix(x),
+                Backends::C(inner) => inner.mix(x),
+                Backends::D(inner) => inner.mix(x),
+            }
+        }
+        pub async fn fetch(&self, x: u64) -> u64 {
+            match self {
+                Backends::A(inner) => inner.fetch(x).await,
+                Backends::B(inner) => inner.fetch(x).await,
+                Backends::C(inner) => inner.fetch(x).await,
+                Backends::D(inner) => inner.fetch(x).await,
+            }
+        }
+    }
+}
+
+mod with_enum_dispatch {
+    use super::V;
+    use enum_dispatch::enum_dispatch;
+
+    // `enum_dispatch` can't forward `impl Future` return types
+    #[enum_dispatch]
+    pub trait Backend {
+        fn get(&self, x: u64) -> u64;
+        fn bump(&mut self, x: u64);
+        fn into_value(self) -> u64;
+        fn mix<T: Into<u64>>(&self, x: T) -> u64;
+    }
+
+    #[enum_dispatch(Backend)]
+    #[derive(Clone)]
+    pub enum Backends {
+        A(V<1>),
+        B(V<3>),
+        C(V<5>),
+        D(V<7>),
+    }
+
+    impl<const N: u64> Backend for V<N> {
+        #[inline]
+        fn get(&self, x: u64) -> u64 {
+            super::with_disponent::Backend::get(self, x)
+        }
+        #[inline]
+        fn bump(&mut self, x: u64) {
+            super::with_disponent::Backend::bump(self, x)
+        }
+        #[inline]
+        fn into_value(self) -> u64 {
+            super::with_disponent::Backend::into_value(self)
+        }
+        #[inline]
+        fn mix<T: Into<u64>>(&self, x: T) -> u64 {
+            super::with_disponent::Backend::mix(self, x)
+        }
+    }
+}
+
+mod with_dyn {
+    use super::V;
+
+    // Generic and async methods are not dyn compatible
+    pub trait Backend {
+        fn get(&self, x: u64) -> u64;
+        fn bump(&mut self, x: u64);
+        fn into_value(self: Box<Self>) -> u64;
+        fn box_clone(&self) -> Box<dyn Backend>;
+    }
+
+    impl<const N: u64> Backend for V<N> {
+        #[inline]
+        fn get(&self, x: u64) -> u64 {
+            super::with_disponent::Backend::get(self, x)
+        }
+        #[inline]
+        fn bump(&mut self, x: u64) {
+            super::with_disponent::Backend::bump(self, x)
+        }
+        #[inline]
+        fn into_value(self: Box<Self>) -> u64 {
+            super::with_disponent::Backend::into_value(*self)
+        }
+        fn box_clone(&self) -> Box<dyn Backend> {
+            Box::new(self.clone())
+        }
+    }
+
+    impl Clone for Box<dyn Backend> {
+        fn clone(&self) -> Self {
+            self.box_clone()
+        }
+    }
+}
+
+/// Build the same pseudo-random sequence of variants for every approach.
+macro_rules! values {
+    () => {
+        (
+            values!(@ with_disponent::Backends),
+            values!(@ handwritten::Backends),
+            values!(@ with_enum_dispatch::Backends),
+            common::variant_indices(4, LEN)
+                .into_iter()
+                .map(|i| -> Box<dyn with_dyn::Backend> {
+                    match i {
+                        0 => Box::new(V::<1>(i as u64)),
+                        1 => Box::new(V::<3>(i as u64)),
+                        2 => Box::new(V::<5>(i as u64)),
+                        _ => Box::new(V::<7>(i as u64)),
+                    }
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+    (@ $enum:path) => {{
+        use $enum as E;
+        common::variant_indices(4, LEN)
+            .into_iter()
+            .map(|i| match i {
+                0 => E::A(V(i as u64)),
+                1 => E::B(V(i as u64)),
+                2 => E::C(V(i as u64)),
+                _ => E::D(V(i as u64)),
+            })
+            .collect::<Vec<_>>()
+    }};
+}
+
+/// Poll a future that is ready immediately, so only the dispatch is measured.
+fn now<F: Future>(f: F) -> F::Output {
+    match pin!(f).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("benchmark futures are always ready"),
+    }
+}
+
+fn main() {
+    use with_disponent::Backend as _;
+    use with_enum_dispatch::Backend as _;
+
+    let (disponent, handwritten, enum_dispatch, dyns) = values!();
+    let per_call = |ns: f64| Some(ns / LEN as f64);
+
+    let mut table = common::Table::new(
+        format!(
+            "disponent {} vs. alternatives, 4 variants (time per call)",
+            env!("CARGO_PKG_VERSION")
+        ),
+        &["disponent", "match", "enum_dispatch", "dyn Trait"],
+    );
+
+    table.row(
+        "`&self`",
+        vec![
+            per_call(common::bench(|| {
+                black_box(&disponent).iter().fold(0, |acc, v| v.get(acc))
+            })),
+            per_call(common::bench(|| {
+                black_box(&handwritten).iter().fold(0, |acc, v| v.get(acc))
+            })),
+            per_call(common::bench(|| {
+                black_box(&enum_dispatch)
+                    .iter()
+                    .fold(0, |acc, v| v.get(acc))
+            })),
+            per_call(common::bench(|| {
+                black_box(&dyns).iter().fold(0, |acc, v| v.get(acc))
+            })),
+        ],
+    );
+
+    let (mut disponent_mut, mut handwritten_mut, mut enum_dispatch_mut, mut dyns_mut) = values!();
+    table.row(
+        "`&mut self`",
+        vec![
+            per_call(common::bench(|| {
+                black_box(&mut disponent_mut)
+                    .iter_mut()
+                    .for_each(|v| v.bump(3))
+            })),
+            per_call(common::bench(|| {
+                black_box(&mut handwritten_mut)
+                    .iter_mut()
+                    .for_each(|v| v.bump(3))
+            })),
+            per_call(common::bench(|| {
+                black_box(&mut enum_dispatch_mut)
+                    .iter_mut()
+                    .for_each(|v| v.bump(3))
+            })),
+            per_call(common::bench(|| {
+                black_box(&mut dyns_mut).iter_mut().for_each(|v| v.bump(3))
+            })),
+        ],
+    );
+
+    table.row(
+        "`self`",
+        vec![
+            per_call(common::bench_batched(
+                || disponent.clone(),
+                |values| values.into_iter().map(|v| v.into_value()).sum::<u64>(),
+            )),
+            per_call(common::bench_batched(
+                || handwritten.clone(),
+                |values| values.into_iter().map(|v| v.into_value()).sum::<u64>(),
+            )),
+            per_call(common::bench_batched(
+                || enum_dispatch.clone(),
+                |values| values.into_iter().map(|v| v.into_value()).sum::<u64>(),
+            )),
+            per_call(common::bench_batched(
+                || dyns.clone(),
+                |values| values.into_iter().map(|v| v.into_value()).sum::<u64>(),
+            )),
+        ],
+    );
+
+    table.row(
+        "generic",
+        vec![
+            per_call(common::bench(|| {
+                black_box(&disponent)
+                    .iter()
+                    .fold(0, |acc, v| v.mix(acc as u32))
+            })),
+            per_call(common::bench(|| {
+                black_box(&handwritten)
+                    .iter()
+                    .fold(0, |acc, v| v.mix(acc as u32))
+            })),
+            per_call(common::bench(|| {
+                black_box(&enum_dispatch)
+                    .iter()
+                    .fold(0, |acc, v| v.mix(acc as u32))
+            })),
+            None,
+        ],
+    );
+
+    table.row(
+        "async",
+        vec![
+            per_call(common::bench(|| {
+                black_box(&disponent)
+                    .iter()
+                    .fold(0, |acc, v| now(v.fetch(acc)))
+            })),
+            per_call(common::bench(|| {
+                black_box(&handwritten)
+                    .iter()
+                    .fold(0, |acc, v| now(v.fetch(acc)))
+            })),
+            None,
+            None,
+        ],
+    );
+
+    table.print();
+}
@@ -0,0 +1,128 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Result, spanned::Spanned};
+
+/// Generate `arbitrary::Arbitrary` and `proptest::arbitrary::Arbitrary` impls, for
+/// `configure(arbitrary)` and `configure(proptest)` respectively.
+///
+/// Variants are picked with the weight from `#[disponent::weight(n)]`, or 1 by default.
+pub fn impl_arbitrary(
+    enum_def: &syn::ItemEnum,
+    arbitrary: bool,
+    proptest: bool,
+) -> Result<TokenStream> {
+    if !enum_def.generics.params.is_empty() {
+        let option = if arbitrary { "arbitrary" } else { "proptest" };
+        return Err(syn::Error::new(
+            enum_def.generics.span(),
+            format!("`{option}` is not supported for generic enums"),
+        ));
+    }
+
+    let mut variants = Vec::new();
+    for variant in &enum_def.variants {
+        let syn::Fields::Unnamed(fields) = &variant.fields else {
+            continue;
+        };
+        let Some(field) = fields.unnamed.first() else {
+            continue;
+        };
+        let weight = match crate::helper_attr(&variant.attrs, "weight") {
+            Some(attr) => attr.parse_args::<syn::LitInt>()?.base10_parse::<u32>()?,
+            None => 1,
+        };
+        let cfg_attrs: Vec<_> = variant
+            .attrs
+            .iter()
+            .filter(|a| crate::forward::is_attr_allowed(a, false))
+            .collect();
+        variants.push((&variant.ident, &field.ty, weight, cfg_attrs));
+    }
+
+    let arbitrary = arbitrary.then(|| impl_fuzz(enum_def, &variants));
+    let proptest = proptest.then(|| impl_proptest(enum_def, &variants));
+
+    Ok(quote! {
+        #arbitrary
+        #proptest
+    })
+}
+
+type Variant<'a> = (&'a syn::Ident, &'a syn::Type, u32, Vec<&'a syn::Attribute>);
+
+fn impl_fuzz(enum_def: &syn::ItemEnum, variants: &[Variant]) -> TokenStream {
+    let enum_ident = &enum_def.ident;
+
+    let weights = variants.iter().map(|(_, _, weight, attrs)| {
+        quote! { #(#attrs)* { total += #weight; } }
+    });
+    let picks = variants.iter().map(|(ident, ty, weight, attrs)| {
+        quote! {
+            #(#attrs)*
+            {
+                if pick < #weight {
+                    return ::core::result::Result::Ok(#enum_ident::#ident(
+                        <#ty as ::arbitrary::Arbitrary<'__disponent_a>>::arbitrary(u)?,
+                    ));
+                }
+                pick -= #weight;
+            }
+        }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl<'__disponent_a> ::arbitrary::Arbitrary<'__disponent_a> for #enum_ident {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'__disponent_a>) -> ::arbitrary::Result<Self> {
+                const TOTAL: u32 = {
+                    let mut total = 0u32;
+                    #(#weights)*
+                    total
+                };
+                if TOTAL == 0 {
+                    return ::core::result::Result::Err(::arbitrary::Error::EmptyChoose);
+                }
+
+                let mut pick = u.int_in_range(0..=TOTAL - 1)?;
+                #(#picks)*
+                let _ = pick;
+                ::core::unreachable!()
+            }
+        }
+    }
+}
+
+fn impl_proptest(enum_def: &syn::ItemEnum, variants: &[Variant]) -> TokenStream {
+    let enum_ident = &enum_def.ident;
+
+    // Same as `prop_oneof!`, which doesn't allow `#[cfg]` on its arms.
+    // `Union` rejects options with a weight of 0, so they are left out.
+    let strategies = variants.iter().filter(|(_, _, weight, _)| *weight > 0).map(
+        |(ident, ty, weight, attrs)| {
+            quote! {
+                #(#attrs)*
+                strategies.push((
+                    #weight,
+                    ::proptest::strategy::Strategy::boxed(::proptest::strategy::Strategy::prop_map(
+                        ::proptest::arbitrary::any::<#ty>(),
+                        #enum_ident::#ident,
+                    )),
+                ));
+            }
+        },
+    );
+
+    quote! {
+        #[automatically_derived]
+        impl ::proptest::arbitrary::Arbitrary for #enum_ident {
+            type Parameters = ();
+            type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                let mut strategies = ::std::vec::Vec::new();
+                #(#strategies)*
+                ::proptest::strategy::Strategy::boxed(::proptest::strategy::Union::new_weighted(strategies))
+            }
+        }
+    }
+}
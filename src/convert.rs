@@ -7,7 +7,11 @@ pub fn impl_from(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
     let (_, ty_generics, where_clause) = enum_def.generics.split_for_impl();
     let enum_ident = &enum_def.ident;
 
-    let impls = variants.iter().map(|(variant_ident, inner_ty, attrs)| {
+    // Dynamic variants always get a `From` impl
+    let variants = variants
+        .iter()
+        .filter(|(_, _, attrs)| !crate::dynamic::is_dynamic(attrs));
+    let impls = variants.map(|(variant_ident, inner_ty, attrs)| {
         let attrs = attrs.iter().filter(|a| is_cfg_attr(a));
         quote! {
             #(#attrs)*
@@ -20,7 +24,22 @@ pub fn impl_from(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
         }
     });
 
-    Ok(quote! { #(#impls)* })
+    let nested = nested_variants(enum_def)?;
+    let nested_impls = nested.iter().map(|n| {
+        let (outer, inner_enum, inner, ty) = (n.outer, n.inner_enum, &n.inner, &n.ty);
+        let attrs = n.attrs.iter().filter(|a| is_cfg_attr(a));
+        quote! {
+            #(#attrs)*
+            #[automatically_derived]
+            impl ::core::convert::From<#ty> for #enum_ident #ty_generics #where_clause {
+                fn from(val: #ty) -> Self {
+                    #enum_ident::#outer(#inner_enum::#inner(val))
+                }
+            }
+        }
+    });
+
+    Ok(quote! { #(#impls)* #(#nested_impls)* })
 }
 
 pub fn impl_try_into(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
@@ -48,6 +67,26 @@ pub fn impl_try_into(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
         }
     });
 
+    let nested = nested_variants(enum_def)?;
+    let nested_impls = nested.iter().map(|n| {
+        let (outer, inner_enum, inner, ty) = (n.outer, n.inner_enum, &n.inner, &n.ty);
+        let attrs = n.attrs.iter().filter(|a| is_cfg_attr(a));
+        quote! {
+            #(#attrs)*
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryInto<#ty> for #enum_ident #ty_generics #where_clause {
+                type Error = #error_ident<#enum_ident #ty_generics>;
+
+                fn try_into(self) -> ::core::result::Result<#ty, Self::Error> {
+                    match self {
+                        #enum_ident::#outer(#inner_enum::#inner(val)) => Ok(val),
+                        other => Err(#error_ident(other)),
+                    }
+                }
+            }
+        }
+    });
+
     Ok(quote! {
         #enum_vis struct #error_ident<#enum_ident #ty_generics>(#enum_ident #ty_generics);
 
@@ -66,9 +105,125 @@ pub fn impl_try_into(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
         impl<#impl_generics> ::core::error::Error for #error_ident<#enum_ident #ty_generics> #where_clause {}
 
         #(#impls)*
+        #(#nested_impls)*
+    })
+}
+
+/// Generate `as_x` and `as_x_mut` accessors for the variants of nested enums, which reach
+/// through both levels.
+pub fn impl_nested_accessors(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
+    let nested = nested_variants(enum_def)?;
+    if nested.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let (impl_generics, ty_generics, where_clause) = enum_def.generics.split_for_impl();
+    let enum_ident = &enum_def.ident;
+    let vis = &enum_def.vis;
+
+    let accessors = nested.iter().map(|n| {
+        let (outer, inner_enum, inner, ty) = (n.outer, n.inner_enum, &n.inner, &n.ty);
+        let attrs: Vec<_> = n.attrs.iter().filter(|a| is_cfg_attr(a)).collect();
+        let name = crate::harness::to_snake_case(inner);
+        let as_ref = quote::format_ident!("as_{}", name);
+        let as_mut = quote::format_ident!("as_{}_mut", name);
+        let doc = format!("The value of `{enum_ident}::{outer}({}::{inner}(..))`, if it is one.", quote!(#inner_enum));
+        quote! {
+            #(#attrs)*
+            #[doc = #doc]
+            #vis fn #as_ref(&self) -> ::core::option::Option<&#ty> {
+                match self {
+                    #enum_ident::#outer(#inner_enum::#inner(val)) => ::core::option::Option::Some(val),
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #(#attrs)*
+            #[doc = #doc]
+            #vis fn #as_mut(&mut self) -> ::core::option::Option<&mut #ty> {
+                match self {
+                    #enum_ident::#outer(#inner_enum::#inner(val)) => ::core::option::Option::Some(val),
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #(#accessors)*
+        }
     })
 }
 
+/// A variant of a nested enum, listed in `#[disponent::nested(Inner(Ty), ...)]` on the variant
+/// that holds it, since the inner enum's variants aren't visible to this macro.
+struct Nested<'a> {
+    outer: &'a syn::Ident,
+    inner_enum: &'a syn::Path,
+    inner: syn::Ident,
+    ty: syn::Type,
+    attrs: &'a Vec<syn::Attribute>,
+}
+
+fn nested_variants(enum_def: &syn::ItemEnum) -> Result<Vec<Nested<'_>>> {
+    let mut nested = Vec::new();
+    for variant in &enum_def.variants {
+        let Some(attr) = crate::helper_attr(&variant.attrs, "nested") else {
+            continue;
+        };
+
+        let inner_enum = match &variant.fields {
+            syn::Fields::Unnamed(f) if f.unnamed.len() == 1 => match &f.unnamed[0].ty {
+                syn::Type::Path(p)
+                    if p.qself.is_none()
+                        && p.path.segments.iter().all(|s| s.arguments.is_empty()) =>
+                {
+                    &p.path
+                }
+                ty => {
+                    return Err(syn::Error::new(
+                        ty.span(),
+                        "`#[disponent::nested(...)]` requires a variant holding a non-generic enum",
+                    ));
+                }
+            },
+            _ => {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "`#[disponent::nested(...)]` requires a newtype variant",
+                ));
+            }
+        };
+
+        let inner_variants = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Variant, syn::Token![,]>::parse_terminated,
+        )?;
+        for inner in inner_variants {
+            let ty = match &inner.fields {
+                syn::Fields::Unnamed(f) if f.unnamed.len() == 1 => f.unnamed[0].ty.clone(),
+                _ => {
+                    return Err(syn::Error::new(
+                        inner.span(),
+                        "Nested variants must be listed as `Variant(Type)`",
+                    ));
+                }
+            };
+            nested.push(Nested {
+                outer: &variant.ident,
+                inner_enum,
+                inner: inner.ident,
+                ty,
+                attrs: &variant.attrs,
+            });
+        }
+    }
+    Ok(nested)
+}
+
 fn extract_variants(
     enum_def: &syn::ItemEnum,
 ) -> Result<Vec<(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)>> {
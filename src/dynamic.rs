@@ -0,0 +1,164 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Result, ext::IdentExt, spanned::Spanned};
+
+/// Whether the variant is marked with `#[disponent::dynamic]` and holds a trait object.
+pub fn is_dynamic(attrs: &[syn::Attribute]) -> bool {
+    crate::helper_attr(attrs, "dynamic").is_some()
+}
+
+/// Whether the variant opted into panicking for methods that can't be called through
+/// `dyn Trait` with `#[disponent::dynamic(fallback = panic)]`.
+pub fn panics(attrs: &[syn::Attribute]) -> Result<bool> {
+    let Some(attr) = crate::helper_attr(attrs, "dynamic") else {
+        return Ok(false);
+    };
+    if let syn::Meta::Path(_) = attr.meta {
+        return Ok(false);
+    }
+
+    attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let key: syn::Ident = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let value: syn::Ident = input.parse()?;
+        if key != "fallback" || value != "panic" {
+            return Err(syn::Error::new(
+                key.span(),
+                "Unknown dynamic option, expected `fallback = panic`",
+            ));
+        }
+        Ok(true)
+    })
+}
+
+/// Check whether a method can be called through `dyn Trait`.
+///
+/// Methods with a by-value receiver, type or const generics, `where Self: ...` bounds, `Self`
+/// in their signature, or an `impl Trait` or async return type can't be called on trait objects.
+pub fn is_dispatchable(sig: &syn::Signature) -> bool {
+    let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first() else {
+        return false;
+    };
+
+    let only_lifetimes = sig
+        .generics
+        .params
+        .iter()
+        .all(|p| matches!(p, syn::GenericParam::Lifetime(_)));
+
+    let self_bounds = sig.generics.where_clause.iter().any(|w| {
+        w.predicates.iter().any(|p| match p {
+            syn::WherePredicate::Type(p) => {
+                crate::table::contains_ident(p.bounded_ty.to_token_stream(), &["Self"])
+            }
+            _ => false,
+        })
+    });
+
+    let typed_args = sig.inputs.iter().skip(1).filter_map(|a| match a {
+        syn::FnArg::Typed(p) => Some(p.ty.to_token_stream()),
+        _ => None,
+    });
+    let output = sig.output.to_token_stream();
+
+    receiver.reference.is_some()
+        && receiver.colon_token.is_none()
+        && sig.asyncness.is_none()
+        && only_lifetimes
+        && !self_bounds
+        && typed_args
+            .chain([output])
+            .all(|tokens| !crate::table::contains_ident(tokens, &["impl", "Self"]))
+}
+
+/// Call the method on the trait object held by the variant.
+pub fn call(
+    sig: &syn::Signature,
+    trait_path: &syn::Path,
+    inner: &syn::Ident,
+    args: &[syn::Ident],
+) -> TokenStream {
+    let method_ident = &sig.ident;
+    let mutability = match sig.inputs.first() {
+        Some(syn::FnArg::Receiver(r)) => r.mutability,
+        _ => None,
+    };
+    quote! { #trait_path::#method_ident(&#mutability **#inner, #(#args),*) }
+}
+
+/// Panic when a method that can't be called through `dyn Trait` is called on the variant, if it
+/// opted in with `#[disponent::dynamic(fallback = panic)]`.
+pub fn fallback(
+    sig: &syn::Signature,
+    trait_path: &syn::Path,
+    enum_ident: &syn::Ident,
+    variant: &syn::Ident,
+    attrs: &[syn::Attribute],
+    inner: &syn::Ident,
+) -> Result<TokenStream> {
+    let trait_name = trait_path
+        .segments
+        .last()
+        .map(|s| s.ident.unraw().to_string())
+        .unwrap_or_default();
+    if !panics(attrs)? {
+        let method = sig.ident.unraw();
+        return Err(syn::Error::new(
+            sig.ident.span(),
+            format!(
+                "`{method}` can't be called through `dyn {trait_name}`, so `{}` needs `#[disponent::override_with({method} = ...)]` or `#[disponent::dynamic(fallback = panic)]`",
+                variant.unraw(),
+            ),
+        ));
+    }
+
+    let message = format!(
+        "`{}` can't be called through `dyn {trait_name}` on `{}::{}`",
+        sig.ident.unraw(),
+        enum_ident.unraw(),
+        variant.unraw(),
+    );
+    Ok(quote! {{
+        let _ = #inner;
+        ::core::panic!(#message)
+    }})
+}
+
+/// Implement `From` for the trait objects of dynamic variants.
+pub fn impl_from(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = enum_def.generics.split_for_impl();
+    let enum_ident = &enum_def.ident;
+
+    let mut impls = Vec::new();
+    for variant in &enum_def.variants {
+        if !is_dynamic(&variant.attrs) {
+            continue;
+        }
+        panics(&variant.attrs)?;
+        let ty = match &variant.fields {
+            syn::Fields::Unnamed(f) if f.unnamed.len() == 1 => &f.unnamed[0].ty,
+            _ => {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "`#[disponent::dynamic]` requires a newtype variant holding a trait object",
+                ));
+            }
+        };
+        let ident = &variant.ident;
+        let cfg_attrs = variant
+            .attrs
+            .iter()
+            .filter(|a| crate::forward::is_attr_allowed(a, false));
+        impls.push(quote! {
+            #(#cfg_attrs)*
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#ty> for #enum_ident #ty_generics #where_clause {
+                fn from(val: #ty) -> Self {
+                    #enum_ident::#ident(val)
+                }
+            }
+        });
+    }
+
+    Ok(quote! { #(#impls)* })
+}
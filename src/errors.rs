@@ -0,0 +1,171 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{ext::IdentExt, spanned::Spanned};
+
+/// The associated type `X` in a `Result<T, Self::X>` return type.
+pub fn result_error(output: &syn::ReturnType) -> Option<&syn::Ident> {
+    let syn::ReturnType::Type(_, ty) = output else {
+        return None;
+    };
+    let syn::Type::Path(p) = &**ty else {
+        return None;
+    };
+    let segment = p.path.segments.last().filter(|s| s.ident == "Result")?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let (2, Some(syn::GenericArgument::Type(syn::Type::Path(error)))) =
+        (args.args.len(), args.args.last())
+    else {
+        return None;
+    };
+
+    match error.path.segments.iter().collect::<Vec<_>>()[..] {
+        [this, assoc]
+            if error.qself.is_none()
+                && this.ident == "Self"
+                && this.arguments.is_empty()
+                && assoc.arguments.is_empty() =>
+        {
+            Some(&assoc.ident)
+        }
+        _ => None,
+    }
+}
+
+/// Associated types of the trait that are used as errors in `Result<T, Self::X>` return types.
+pub fn error_types(trait_def: &syn::ItemTrait) -> Vec<&syn::Ident> {
+    let outputs: Vec<_> = trait_def
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::TraitItem::Fn(m) => Some(crate::forward::extract_future_output(&m.sig.output).1),
+            _ => None,
+        })
+        .collect();
+
+    trait_def
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::TraitItem::Type(t) => Some(&t.ident),
+            _ => None,
+        })
+        .filter(|assoc| outputs.iter().any(|o| result_error(o) == Some(*assoc)))
+        .collect()
+}
+
+/// The name of the unified error enum for an associated type, like `FooOrBarError`.
+pub fn unified_ident(enum_ident: &syn::Ident, assoc: &syn::Ident) -> syn::Ident {
+    format_ident!("{}{}", enum_ident, assoc)
+}
+
+/// Generate an enum that holds the error of any variant, with `From`, `Debug`, `Display`
+/// and `Error` impls that forward to the variant's error.
+pub fn unified_enum(
+    enum_def: &syn::ItemEnum,
+    trait_path: &syn::Path,
+    assoc: &syn::Ident,
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+) -> TokenStream {
+    let vis = &enum_def.vis;
+    let ident = unified_ident(&enum_def.ident, assoc);
+    let doc = format!("The `{assoc}` of any variant of `{}`.", enum_def.ident);
+
+    let cfg_attrs = |attrs: &[syn::Attribute]| {
+        attrs
+            .iter()
+            .filter(|a| crate::forward::is_attr_allowed(a, false))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let error_ty = |ty: &syn::Type| quote! { <#ty as #trait_path>::#assoc };
+
+    let error_variants = variants.iter().map(|(v, ty, attrs)| {
+        let attrs = cfg_attrs(attrs);
+        let error_ty = error_ty(ty);
+        quote! { #(#attrs)* #v(#error_ty), }
+    });
+
+    // Variants with the same type have the same error, which can't be converted to either of
+    // them. Other shared errors can only be found by the compiler, which reports the conflicting
+    // impl at the variant.
+    let from_impls = variants
+        .iter()
+        .filter(|(_, ty, _)| {
+            let ty = ty.to_token_stream().to_string();
+            variants
+                .iter()
+                .filter(|(_, other, _)| other.to_token_stream().to_string() == ty)
+                .count()
+                == 1
+        })
+        .map(|(v, ty, attrs)| {
+            let attrs = cfg_attrs(attrs);
+            let error_ty = error_ty(ty);
+            quote_spanned! {v.span()=>
+                #(#attrs)*
+                #[automatically_derived]
+                impl ::core::convert::From<#error_ty> for #ident {
+                    fn from(error: #error_ty) -> Self {
+                        #ident::#v(error)
+                    }
+                }
+            }
+        });
+
+    let arms = |body: TokenStream| {
+        let arms = variants.iter().map(|(v, _, attrs)| {
+            let attrs = cfg_attrs(attrs);
+            quote! { #(#attrs)* #ident::#v(error) => #body, }
+        });
+        quote! { match self { #(#arms)* } }
+    };
+    let debug = {
+        let arms = variants.iter().map(|(v, _, attrs)| {
+            let attrs = cfg_attrs(attrs);
+            let name = v.unraw().to_string();
+            quote! { #(#attrs)* #ident::#v(error) => f.debug_tuple(#name).field(error).finish(), }
+        });
+        quote! { match self { #(#arms)* } }
+    };
+    let display = arms(quote! { ::core::fmt::Display::fmt(error, f) });
+    let source = arms(quote! { ::core::error::Error::source(error) });
+
+    quote! {
+        #[doc = #doc]
+        #vis enum #ident {
+            #(#error_variants)*
+        }
+
+        #(#from_impls)*
+
+        #[automatically_derived]
+        impl ::core::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { #debug }
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { #display }
+        }
+
+        #[automatically_derived]
+        impl ::core::error::Error for #ident {
+            fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> { #source }
+        }
+    }
+}
+
+/// Check that `unify_errors` is used on a non-generic enum and trait.
+pub fn check_generics(enum_def: &syn::ItemEnum, trait_def: &syn::ItemTrait) -> syn::Result<()> {
+    for generics in [&enum_def.generics, &trait_def.generics] {
+        if !generics.params.is_empty() {
+            return Err(syn::Error::new(
+                generics.span(),
+                "`unify_errors` is not supported for generic enums or traits",
+            ));
+        }
+    }
+    Ok(())
+}
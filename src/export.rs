@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Result,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+/// `export_macro` or `export_macro(module = crate::path::to::module)` in configure.
+#[derive(Default)]
+pub struct ExportConfig {
+    module: Option<syn::Path>,
+}
+
+impl Parse for ExportConfig {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut config = ExportConfig::default();
+
+        while !input.is_empty() {
+            let ident: syn::Ident = input.parse()?;
+            input.parse::<syn::Token![=]>()?;
+            match ident.to_string().as_str() {
+                "module" => config.module = Some(input.call(syn::Path::parse_mod_style)?),
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "Unknown export_macro option, expected `module`",
+                    ));
+                }
+            }
+            if input.peek(syn::Token![,]) {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Generate an exported `foo_or_bar_dispatch!` macro that matches on the variants, so other
+/// crates can forward their own traits without listing the variants again.
+///
+/// The macro is expanded in other crates, so the enum is named through `$crate` and the module
+/// from `export_macro(module = ...)`, which defaults to the crate root.
+pub fn export_macro(enum_def: &syn::ItemEnum, config: &ExportConfig) -> Result<TokenStream> {
+    if !enum_def.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            enum_def.generics.span(),
+            "`export_macro` is not supported for generic enums",
+        ));
+    }
+
+    // `#[cfg]` would be evaluated with the configuration of the crate using the macro
+    if let Some(variant) = enum_def.variants.iter().find(|v| {
+        v.attrs
+            .iter()
+            .any(|a| a.path().is_ident("cfg") || a.path().is_ident("cfg_attr"))
+    }) {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            "`export_macro` cannot be used when variants have `#[cfg]` attributes",
+        ));
+    }
+
+    let enum_ident = &enum_def.ident;
+    let module = config
+        .module
+        .iter()
+        .flat_map(|path| &path.segments)
+        .map(|s| &s.ident)
+        .skip_while(|ident| *ident == "crate");
+    let enum_path = quote! { $crate #(::#module)* ::#enum_ident };
+    let macro_ident = format_ident!("{}_dispatch", crate::harness::to_snake_case(enum_ident));
+
+    let arms = enum_def.variants.iter().map(|v| {
+        let ident = &v.ident;
+        quote! { #enum_path::#ident(inner) => <_ as $trait_>::$method(inner $(, $arg)*), }
+    });
+    let match_arms = enum_def.variants.iter().map(|v| {
+        let ident = &v.ident;
+        quote! { #enum_path::#ident($inner) => $body, }
+    });
+
+    let doc = format!(
+        "Match on the variants of `{enum_ident}`, binding the inner value.\n\n\
+         `{macro_ident}!(value, inner => expr)` evaluates `expr` for the variant of `value`, and \
+         `{macro_ident}!(impl Trait {{ fn method(&self, arg: Type) -> Ret; }})` implements \
+         `Trait` for `{enum_ident}` by forwarding each method to the variants."
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[macro_export]
+        macro_rules! #macro_ident {
+            // The receiver is matched literally so `self` in the generated body has the same hygiene
+            // as the one in the signature. The lifetimes never match and only drive the repetitions.
+            (impl $trait_:path {
+                $(
+                    $(#[$meta:meta])*
+                    fn $method:ident $(<$($lifetime:lifetime),* $(,)?>)? (
+                        $(& $($self_lifetime:lifetime)? $(mut $($_mut:lifetime)?)?)?
+                        self $(, $arg:ident: $ty:ty)* $(,)?
+                    ) $(-> $ret:ty)?;
+                )*
+            }) => {
+                #[automatically_derived]
+                impl $trait_ for #enum_path {
+                    $(
+                        $(#[$meta])*
+                        fn $method $(<$($lifetime),*>)? (
+                            $(& $($self_lifetime)? $(mut $($_mut)?)?)? self $(, $arg: $ty)*
+                        ) $(-> $ret)? {
+                            match self {
+                                #(#arms)*
+                            }
+                        }
+                    )*
+                }
+            };
+            ($value:expr, $inner:ident => $body:expr) => {
+                match $value {
+                    #(#match_arms)*
+                }
+            };
+        }
+    })
+}
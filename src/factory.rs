@@ -0,0 +1,158 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Result, spanned::Spanned};
+
+/// Generate `from_name` and `names` to construct variants from a name and a string of arguments.
+///
+/// Variants are constructed with `Default` unless they have `#[disponent::factory(FromStr)]`
+/// or `#[disponent::factory(path::to::ctor)]`.
+pub fn impl_factory(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
+    if !enum_def.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            enum_def.generics.span(),
+            "`factory` is not supported for generic enums",
+        ));
+    }
+
+    let enum_ident = &enum_def.ident;
+    let vis = &enum_def.vis;
+    let error_ident = format_ident!("{}FactoryError", enum_ident);
+    let boxed_error = quote! {
+        ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync>
+    };
+
+    let mut names: Vec<syn::LitStr> = Vec::new();
+    let mut arms = Vec::new();
+    let mut entries = Vec::new();
+    let mut counts = Vec::new();
+
+    for variant in &enum_def.variants {
+        let name = crate::variant_name(variant)?;
+        let cfg_attrs: Vec<_> = variant
+            .attrs
+            .iter()
+            .filter(|a| crate::forward::is_attr_allowed(a, false))
+            .collect();
+
+        // Variants with `#[cfg]`s may share a name if they are never enabled together
+        if cfg_attrs.is_empty()
+            && let Some(other) = names.iter().find(|n| n.value() == name.value())
+        {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("Duplicate variant name `{}`", other.value()),
+            ));
+        }
+
+        let syn::Fields::Unnamed(fields) = &variant.fields else {
+            continue;
+        };
+        let Some(field) = fields.unnamed.first() else {
+            continue;
+        };
+        let ty = &field.ty;
+        let ident = &variant.ident;
+
+        let constructor = match crate::helper_attr(&variant.attrs, "factory") {
+            None => quote! { <#ty as ::core::default::Default>::default() },
+            Some(attr) => {
+                let path: syn::Path = attr.parse_args()?;
+                let invalid = quote! {
+                    |error| #error_ident::Invalid { name: #name, error: ::core::convert::Into::into(error) }
+                };
+                if path.is_ident("Default") {
+                    quote! { <#ty as ::core::default::Default>::default() }
+                } else if path.is_ident("FromStr") {
+                    quote! { <#ty as ::core::str::FromStr>::from_str(args).map_err(#invalid)? }
+                } else {
+                    quote! { #path(args).map_err(#invalid)? }
+                }
+            }
+        };
+
+        arms.push(quote! {
+            #(#cfg_attrs)*
+            #name => ::core::result::Result::Ok(#enum_ident::#ident(#constructor)),
+        });
+        entries.push(quote! { #(#cfg_attrs)* { items[i] = #name; i += 1; } });
+        counts.push(quote! { #(#cfg_attrs)* { len += 1; } });
+        if cfg_attrs.is_empty() {
+            names.push(name);
+        }
+    }
+
+    let error_doc = format!("Error returned by `{enum_ident}::from_name`.");
+
+    Ok(quote! {
+        #[doc = #error_doc]
+        #[derive(Debug)]
+        #vis enum #error_ident {
+            /// No variant has the given name.
+            UnknownVariant { name: ::std::string::String },
+            /// The variant's constructor failed.
+            Invalid { name: &'static str, error: #boxed_error },
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #error_ident::UnknownVariant { name } => {
+                        ::core::write!(f, "unknown variant `{}`, expected one of ", name)?;
+                        for (i, name) in #enum_ident::names().iter().enumerate() {
+                            if i > 0 {
+                                f.write_str(", ")?;
+                            }
+                            ::core::write!(f, "`{}`", name)?;
+                        }
+                        ::core::result::Result::Ok(())
+                    }
+                    #error_ident::Invalid { name, error } => {
+                        ::core::write!(f, "invalid arguments for `{}`: {}", name, error)
+                    }
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::error::Error for #error_ident {
+            fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
+                match self {
+                    #error_ident::UnknownVariant { .. } => ::core::option::Option::None,
+                    #error_ident::Invalid { error, .. } => ::core::option::Option::Some(&**error),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #enum_ident {
+            /// Construct the variant with the given name, passing `args` to its constructor.
+            #vis fn from_name(name: &str, args: &str) -> ::core::result::Result<Self, #error_ident> {
+                let _ = args;
+                match name {
+                    #(#arms)*
+                    _ => ::core::result::Result::Err(#error_ident::UnknownVariant {
+                        name: ::std::string::ToString::to_string(name),
+                    }),
+                }
+            }
+
+            /// The names accepted by `from_name`.
+            #vis fn names() -> &'static [&'static str] {
+                const LEN: usize = {
+                    let mut len = 0;
+                    #(#counts)*
+                    len
+                };
+                const ITEMS: [&str; LEN] = {
+                    let mut items = [""; LEN];
+                    let mut i = 0;
+                    #(#entries)*
+                    let _ = i;
+                    items
+                };
+                &ITEMS
+            }
+        }
+    })
+}
@@ -1,12 +1,15 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Result, spanned::Spanned};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{Result, ext::IdentExt, spanned::Spanned, visit_mut::VisitMut};
 
-use crate::InherentConfig;
+use crate::{
+    Configure, Dispatch, InherentConfig, Inline, dynamic, errors,
+    overrides::{self, Override},
+    stats, table, trace, wrapper,
+};
 
 pub fn forward_to_variant(
-    inherent: Option<&InherentConfig>,
-    inline: bool,
+    config: &Configure,
     enum_def: &syn::ItemEnum,
     trait_def: &syn::ItemTrait,
     remote_path: Option<&syn::Path>,
@@ -18,8 +21,54 @@ pub fn forward_to_variant(
         ));
     }
 
+    // Associated types are only supported as errors that get unified into one enum
+    let error_types = match config.unify_errors {
+        true => {
+            errors::check_generics(enum_def, trait_def)?;
+            if let Some(variant) = enum_def
+                .variants
+                .iter()
+                .find(|v| dynamic::is_dynamic(&v.attrs))
+            {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "`unify_errors` is not supported with `#[disponent::dynamic]` variants",
+                ));
+            }
+            errors::error_types(trait_def)
+        }
+        false => Vec::new(),
+    };
+
+    // Generic associated types are forwarded through a companion enum
+    let gats: Vec<_> = trait_def
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::TraitItem::Type(t) if !t.generics.params.is_empty() => Some(t),
+            _ => None,
+        })
+        .collect();
+    if let Some(gat) = gats.first()
+        && (!enum_def.generics.params.is_empty() || !trait_def.generics.params.is_empty())
+    {
+        return Err(syn::Error::new(
+            gat.span(),
+            "Generic associated types are not supported for generic enums or traits",
+        ));
+    }
+
     for item in &trait_def.items {
         if let Some(msg) = match item {
+            syn::TraitItem::Type(t)
+                if error_types.contains(&&t.ident) && t.generics.params.is_empty() =>
+            {
+                None
+            }
+            syn::TraitItem::Type(t) if !t.generics.params.is_empty() => None,
+            syn::TraitItem::Type(_) if config.unify_errors => Some(
+                "Associated types are only supported as errors in `Result<T, Self::Error>` return types",
+            ),
             syn::TraitItem::Type(_) => Some("Associated types not supported"),
             syn::TraitItem::Const(_) => Some("Associated constants not supported"),
             _ => None,
@@ -54,15 +103,29 @@ pub fn forward_to_variant(
     let local_trait_path: syn::Path = trait_def.ident.clone().into();
     let trait_path = remote_path.unwrap_or(&local_trait_path);
 
-    let variant_bounds: Vec<_> = if !trait_def.generics.params.is_empty() {
-        variants
-            .iter()
-            .map(|(_, ty, _)| quote! { #ty: #trait_path #trait_ty_generics })
-            .collect()
-    } else {
-        Default::default()
-    };
+    // Variants of a generic trait, or that mention the enum's type or const parameters, can't be
+    // assumed to implement the trait
+    let enum_params: Vec<_> = enum_def
+        .generics
+        .params
+        .iter()
+        .filter_map(|p| match p {
+            syn::GenericParam::Type(t) => Some(t.ident.to_string()),
+            syn::GenericParam::Const(c) => Some(c.ident.to_string()),
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+    let enum_params: Vec<_> = enum_params.iter().map(String::as_str).collect();
+    let variant_bounds: Vec<_> = static_variants(&variants)
+        .into_iter()
+        .filter(|(_, ty, _)| {
+            !trait_def.generics.params.is_empty()
+                || table::contains_ident(ty.to_token_stream(), &enum_params)
+        })
+        .map(|(_, ty, _)| quote! { #ty: #trait_path #trait_ty_generics })
+        .collect();
 
+    let inherent = config.inherent.as_ref();
     let trait_generics = (inherent.is_some() && !trait_def.generics.params.is_empty()).then_some((
         &trait_def.generics,
         trait_where_clause,
@@ -74,48 +137,151 @@ pub fn forward_to_variant(
         InherentConfig::Explicit(vis) => vis,
     });
 
-    let methods: Vec<_> = trait_def
+    let overrides = overrides::collect(trait_def, &variants)?;
+
+    for item in &trait_def.items {
+        let syn::TraitItem::Fn(m) = item else {
+            continue;
+        };
+        let Some(skip) = crate::helper_attr(&m.attrs, "skip") else {
+            continue;
+        };
+        if m.default.is_none() {
+            return Err(syn::Error::new(
+                skip.span(),
+                "`#[disponent::skip]` can only be used on methods with a default body",
+            ));
+        }
+        if let Some(o) = overrides.iter().find(|o| o.method == m.sig.ident) {
+            return Err(syn::Error::new(
+                o.with.span(),
+                format!("Cannot override skipped method `{}`", o.method),
+            ));
+        }
+    }
+
+    // Methods that dispatch to a variant, used to index the call counters
+    let forwarded: Vec<_> = trait_def
         .items
         .iter()
         .filter_map(|item| match item {
+            syn::TraitItem::Fn(m) if crate::helper_attr(&m.attrs, "skip").is_none() => {
+                Some(&m.sig.ident)
+            }
+            _ => None,
+        })
+        .collect();
+
+    let (methods, mut items): (Vec<_>, Vec<_>) = trait_def
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            // Skipped methods use the trait's default body, which the trait impl gets for free
+            syn::TraitItem::Fn(m)
+                if inherent.is_none() && crate::helper_attr(&m.attrs, "skip").is_some() =>
+            {
+                None
+            }
             syn::TraitItem::Fn(m) => Some(generate_method(
                 inherent_vis,
-                inline,
+                config,
                 m,
-                enum_ident,
+                enum_def,
                 trait_path,
                 &variants,
+                &overrides,
+                config
+                    .stats
+                    .then(|| forwarded.iter().position(|f| **f == m.sig.ident))
+                    .flatten()
+                    .map(|i| (i, forwarded.len())),
                 trait_generics,
+                !trait_def.generics.params.is_empty(),
             )),
             _ => None,
         })
-        .collect::<Result<_>>()?;
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    if config.stats {
+        items.push(stats::impl_stats(enum_def, &forwarded));
+    }
+
+    items.extend(
+        error_types
+            .iter()
+            .map(|assoc| errors::unified_enum(enum_def, trait_path, assoc, &variants)),
+    );
+    let mut assoc_types: Vec<_> = error_types
+        .iter()
+        .map(|assoc| {
+            let unified = errors::unified_ident(enum_ident, assoc);
+            quote! { type #assoc = #unified; }
+        })
+        .collect();
+
+    for gat in &gats {
+        let gat_ident = &gat.ident;
+        let companion = quote::format_ident!("{}{}", enum_ident, gat_ident);
+        let doc = format!("The `{gat_ident}` of any variant of `{enum_ident}`.");
+        items.push(wrapper::companion_enum(
+            &companion,
+            &doc,
+            &enum_def.vis,
+            trait_path,
+            gat,
+            &static_variants(&variants),
+        )?);
+
+        let (impl_generics, ty_generics, where_clause) = gat.generics.split_for_impl();
+        assoc_types.push(quote! {
+            type #gat_ident #impl_generics = #companion #ty_generics #where_clause;
+        });
+    }
 
     Ok(if inherent.is_some() {
-        let where_clause = build_where_clause(enum_where_clause, None, &[]);
+        // Bounds that mention trait parameters are added to each method instead
+        let inherent_bounds = match trait_generics {
+            Some(_) => &[][..],
+            None => &variant_bounds,
+        };
+        let where_clause = build_where_clause(enum_where_clause, None, inherent_bounds);
         quote! {
             #[automatically_derived]
             impl #enum_impl_generics #enum_ident #enum_ty_generics #where_clause { #(#methods)* }
+            #(#items)*
         }
     } else {
         let where_clause =
             build_where_clause(enum_where_clause, trait_where_clause, &variant_bounds);
+        // The enum upholds an `unsafe trait`'s contract if every variant does
+        let unsafety = &trait_def.unsafety;
         quote! {
             #[automatically_derived]
-            impl #enum_impl_generics #trait_impl_generics #trait_path #trait_ty_generics for #enum_ident #enum_ty_generics #where_clause { #(#methods)* }
+            #unsafety impl #enum_impl_generics #trait_impl_generics #trait_path #trait_ty_generics for #enum_ident #enum_ty_generics #where_clause {
+                #(#assoc_types)*
+                #(#methods)*
+            }
+            #(#items)*
         }
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn generate_method(
     inherent: Option<&syn::Visibility>,
-    inline: bool,
+    config: &Configure,
     method: &syn::TraitItemFn,
-    enum_ident: &syn::Ident,
+    enum_def: &syn::ItemEnum,
     trait_path: &syn::Path,
     variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+    overrides: &[Override],
+    stats: Option<(usize, usize)>,
     trait_generics: Option<(&syn::Generics, Option<&syn::WhereClause>, &Vec<TokenStream>)>,
-) -> Result<TokenStream> {
+    generic_trait: bool,
+) -> Result<(TokenStream, TokenStream)> {
+    let enum_ident = &enum_def.ident;
     let mut sig = method.sig.clone();
 
     // Check for unsupported self types like `self: Arc<Self>`
@@ -131,7 +297,12 @@ fn generate_method(
         ));
     }
 
-    let (is_impl_future, ret) = extract_future_output(&sig.output);
+    // Skipped methods keep their signature as is, since the default body already returns a future
+    let skip = crate::helper_attr(&method.attrs, "skip").is_some();
+    let (is_impl_future, ret) = match skip {
+        true => (false, sig.output.clone()),
+        false => extract_future_output(&sig.output),
+    };
     sig.output = ret;
     let is_async = is_impl_future || sig.asyncness.is_some();
     sig.asyncness = is_async.then(|| syn::Token![async](proc_macro2::Span::call_site()));
@@ -171,56 +342,370 @@ fn generate_method(
         );
     }
 
-    // Replace Self with enum ident in non-receiver arguments and return type
+    // Errors of each variant are wrapped in the unified error enum
+    let unified_error = config
+        .unify_errors
+        .then(|| errors::result_error(&sig.output))
+        .flatten()
+        .map(|assoc| errors::unified_ident(enum_ident, assoc));
+    if inherent.is_some()
+        && let Some(unified) = &unified_error
+        && let syn::ReturnType::Type(_, ty) = &mut sig.output
+        && let syn::Type::Path(p) = &mut **ty
+        && let Some(syn::PathArguments::AngleBracketed(args)) =
+            p.path.segments.last_mut().map(|s| &mut s.arguments)
+        && let Some(error) = args.args.last_mut()
+    {
+        *error = syn::parse_quote!(#unified);
+    }
+
+    // Replace `Self` with the enum in the signature. Each variant expects and returns its own
+    // type, so only a plain `Self` return type can be forwarded, by wrapping the variant's value.
+    let (_, enum_ty_generics, _) = enum_def.generics.split_for_impl();
+    let mut replace = ReplaceSelf {
+        enum_ty: syn::parse_quote!(#enum_ident #enum_ty_generics),
+        found: Vec::new(),
+        projections: Vec::new(),
+    };
+    for param in &mut sig.generics.params {
+        replace.visit_generic_param_mut(param);
+    }
     for p in sig.inputs.iter_mut().skip(1).filter_map(|a| match a {
         syn::FnArg::Typed(p) => Some(p),
         _ => None,
     }) {
-        replace_self(&mut p.ty, enum_ident);
+        replace.visit_type_mut(&mut p.ty);
     }
-    if let syn::ReturnType::Type(_, t) = &mut sig.output {
-        replace_self(t, enum_ident);
+    let in_inputs = std::mem::take(&mut replace.found);
+    let returns_self = matches!(&sig.output, syn::ReturnType::Type(_, ty) if is_self(ty));
+    // Generic associated types are forwarded as the whole return type, and unified errors as the
+    // error of `Result<T, Self::Error>`. Projections anywhere else are collected and rejected.
+    let forwards_gat = wrapper::gat_output(&sig.output).is_some();
+    if let syn::ReturnType::Type(_, ty) = &mut sig.output
+        && let syn::Type::Path(p) = &mut **ty
+        && let Some(segment) = p.path.segments.last_mut()
+        && (forwards_gat || unified_error.is_some())
+    {
+        match &mut segment.arguments {
+            syn::PathArguments::AngleBracketed(args) if !forwards_gat => {
+                let len = args.args.len();
+                for arg in args.args.iter_mut().take(len - 1) {
+                    replace.visit_generic_argument_mut(arg);
+                }
+            }
+            arguments => replace.visit_path_arguments_mut(arguments),
+        }
+    } else {
+        replace.visit_return_type_mut(&mut sig.output);
     }
 
-    // Check for reserved parameter names
-    let inner = quote::format_ident!("__disponent_inner");
-    for p in sig.inputs.iter().skip(1).filter_map(|a| match a {
-        syn::FnArg::Typed(p) => Some(p),
-        _ => None,
-    }) {
-        if let syn::Pat::Ident(pat) = &*p.pat
-            && pat.ident == inner
-        {
+    if !skip {
+        if let Some(span) = in_inputs.first() {
+            return Err(syn::Error::new(
+                *span,
+                "`Self` can't be forwarded in arguments, since each variant expects its own type",
+            ));
+        }
+        if let (false, Some(span)) = (returns_self, replace.found.first()) {
             return Err(syn::Error::new(
-                pat.ident.span(),
-                "Parameter name `__disponent_inner` is reserved. Use a different name.",
+                *span,
+                "`Self` can only be forwarded as the whole return type, which wraps the variant's value",
             ));
         }
+        if let Some(path) = replace.projections.first() {
+            let assoc = &path.segments[1];
+            let ident = &assoc.ident;
+            let message = match config.unify_errors && assoc.arguments.is_empty() {
+                true => format!(
+                    "`Self::{ident}` can only be forwarded as the error of `Result<T, Self::{ident}>`, since each variant has its own `{ident}`"
+                ),
+                false => format!(
+                    "`Self::{ident}` can't be forwarded, since each variant has its own `{ident}`"
+                ),
+            };
+            return Err(syn::Error::new(path.span(), message));
+        }
+    }
+
+    // The forwarded call needs the bounds of `where Self: ...` from every variant instead of the
+    // enum. Trait impls accept them as long as every variant satisfies them, and report the
+    // bound of the variant that doesn't. Skipped methods run on the enum and keep them as is.
+    if !skip && let Some(where_clause) = &mut sig.generics.where_clause {
+        let bounds: Vec<_> = self_bounds(Some(where_clause))
+            .into_iter()
+            .cloned()
+            .collect();
+        where_clause.predicates = where_clause
+            .predicates
+            .iter()
+            .filter_map(|predicate| match predicate {
+                syn::WherePredicate::Type(p) if p.lifetimes.is_none() && is_self(&p.bounded_ty) => {
+                    let mut p = p.clone();
+                    p.bounds = p.bounds.into_iter().filter(is_sized).collect();
+                    (!p.bounds.is_empty()).then_some(syn::WherePredicate::Type(p))
+                }
+                predicate => Some(predicate.clone()),
+            })
+            .collect();
+        if !bounds.is_empty() {
+            // Unsatisfied bounds are reported at the variant's type
+            for (_, ty, _) in static_variants(variants) {
+                let predicate = respan(quote! { #ty: #(#bounds)+* }, ty.span());
+                where_clause.predicates.push(syn::parse_quote!(#predicate));
+            }
+        }
     }
 
     let attrs = method.attrs.iter().filter(|a| is_attr_allowed(a, true));
     let vis = inherent.map(|v| quote! { #v });
-    let args: Vec<_> = sig
+    let method_ident = &sig.ident;
+
+    // In inherent mode, the default body is copied and its `self` calls resolve to the generated methods
+    if skip && let Some(block) = &method.default {
+        let codegen_attrs = codegen_attrs(method, config, inherent.is_some(), is_async)?;
+        return Ok((
+            quote! { #(#attrs)* #codegen_attrs #vis #sig #block },
+            TokenStream::new(),
+        ));
+    }
+
+    // Rebind every argument to a hygienic name, so any irrefutable pattern can be forwarded
+    // without reserving a name. Plain identifiers keep their name for the docs of inherent methods.
+    let patterns: Vec<_> = sig
         .inputs
-        .iter()
+        .iter_mut()
         .skip(1)
         .filter_map(|a| match a {
-            syn::FnArg::Typed(p) => Some(&p.pat),
+            syn::FnArg::Typed(p) => Some(p),
+            _ => None,
+        })
+        .collect();
+    let names: Vec<_> = patterns
+        .iter()
+        .map(|p| match &*p.pat {
+            syn::Pat::Ident(pat) if pat.subpat.is_none() => Some(pat.ident.clone()),
             _ => None,
         })
         .collect();
 
-    let method_ident = &sig.ident;
-    let arms = variants.iter().map(|(v, _, attrs)| {
-        let variant_attrs = attrs.iter().filter(|a| is_attr_allowed(a, false));
-        let call = quote! { #trait_path::#method_ident(#inner, #(#args),*) };
-        let call = is_async.then(|| quote! { #call.await }).unwrap_or(call);
-        quote! { #(#variant_attrs)* #enum_ident::#v(#inner) => #call, }
-    });
+    let mut args = Vec::new();
+    for (i, (p, name)) in patterns.into_iter().zip(&names).enumerate() {
+        // Synthesized names share the hygiene context, so they must not clash with a plain name
+        let mut arg = match name {
+            Some(name) => name.clone(),
+            None => {
+                let mut arg = format!("arg{i}");
+                while names.iter().flatten().any(|name| *name == arg) {
+                    arg.push('_');
+                }
+                syn::Ident::new(&arg, proc_macro2::Span::call_site())
+            }
+        };
+        arg.set_span(proc_macro2::Span::mixed_site());
+        *p.pat = syn::parse_quote!(#arg);
+        args.push(arg);
+    }
+
+    // The variant's value is bound in the same hygiene context, so it must not shadow an argument
+    let mut inner = String::from("inner");
+    while args.iter().any(|arg| *arg == inner) {
+        inner.push('_');
+    }
+    let inner = syn::Ident::new(&inner, proc_macro2::Span::mixed_site());
+
+    // Generic associated types are wrapped in their companion enum
+    let gat_companion = wrapper::gat_output(&sig.output)
+        .map(|assoc| quote::format_ident!("{}{}", enum_ident, assoc.ident));
+    if inherent.is_some()
+        && let Some(companion) = &gat_companion
+        && let syn::ReturnType::Type(_, ty) = &mut sig.output
+        && let syn::Type::Path(p) = &mut **ty
+    {
+        let args = p.path.segments.last().map(|s| s.arguments.clone());
+        **ty = syn::parse_quote!(#companion #args);
+    }
 
-    let inline_attr = inline.then(|| quote! { #[inline] });
+    // Each variant returns a different type for `impl Trait`, so wrap them in a generated enum
+    let (wrapper_ident, wrapper) = if let Some(companion) = gat_companion {
+        (Some(companion), TokenStream::new())
+    } else if returns_self {
+        (Some(enum_ident.clone()), TokenStream::new())
+    } else if let syn::ReturnType::Type(_, ty) = &sig.output
+        && let syn::Type::ImplTrait(impl_trait) = &**ty
+    {
+        let wrapper_ident =
+            quote::format_ident!("{}{}", enum_ident, wrapper::to_pascal_case(method_ident));
+        let doc = format!("Return type of `{enum_ident}::{method_ident}`.");
+        let wrapper = wrapper::return_enum(
+            &wrapper_ident,
+            &doc,
+            &enum_def.vis,
+            impl_trait,
+            &static_variants(variants),
+        )?;
+        (Some(wrapper_ident), wrapper)
+    } else {
+        (None, TokenStream::new())
+    };
+
+    let dispatchable = dynamic::is_dispatchable(&method.sig);
+    let calls: Vec<_> = variants
+        .iter()
+        .map(|(v, _, attrs)| {
+            let is_dynamic = dynamic::is_dynamic(attrs);
+            let with = overrides
+                .iter()
+                .find(|o| o.variant == **v && o.method == *method_ident)
+                .map(|o| &o.with);
+
+            // Trait objects can't call every method, so their variant falls back to its override,
+            // which returns the method's return type as is, or panics if it opted in
+            if is_dynamic && !dispatchable {
+                return match with {
+                    Some(with) => {
+                        let call = quote_spanned! {with.span()=> (#with)(#inner, #(#args),*) };
+                        Ok(is_async.then(|| quote! { #call.await }).unwrap_or(call))
+                    }
+                    None => dynamic::fallback(&sig, trait_path, enum_ident, v, attrs, &inner),
+                };
+            }
 
-    Ok(quote! { #(#attrs)* #inline_attr #vis #sig { match self { #(#arms)* } } })
+            let call = match with {
+                Some(with) => quote_spanned! {with.span()=> (#with)(#inner, #(#args),*) },
+                None if is_dynamic => dynamic::call(&sig, trait_path, &inner, &args),
+                None => quote! { #trait_path::#method_ident(#inner, #(#args),*) },
+            };
+            // SAFETY: forwarded contract, the caller upholds the same preconditions for the
+            // variant's method as for the enum's
+            let call = match &sig.unsafety {
+                Some(unsafety) => quote! { #unsafety { #call } },
+                None => call,
+            };
+            let call = is_async.then(|| quote! { #call.await }).unwrap_or(call);
+            let call = match &unified_error {
+                Some(unified) => quote! { #call.map_err(#unified::#v) },
+                None => call,
+            };
+            let call = match stats {
+                Some((index, count)) => stats::count(enum_def, v, index, count, call),
+                None => call,
+            };
+            // Async methods run the hooks when the future is polled, around the `.await`, and
+            // await the hooks of `async_hook` if it is set
+            let hook = match (&config.async_hook, is_async) {
+                (Some(hook), true) => Some((hook, quote! { .await })),
+                _ => config.hook.as_ref().map(|hook| (hook, TokenStream::new())),
+            };
+            let call = match hook {
+                Some((hook, await_hook)) => {
+                    let variant = v.unraw().to_string();
+                    let method = method_ident.unraw().to_string();
+                    let result = syn::Ident::new("result", proc_macro2::Span::mixed_site());
+                    quote! {{
+                        <#hook>::before(#variant, #method)#await_hook;
+                        let #result = #call;
+                        <#hook>::after(#variant, #method)#await_hook;
+                        #result
+                    }}
+                }
+                None => call,
+            };
+            Ok(match &wrapper_ident {
+                Some(w) if !is_dynamic => quote! { #w::#v(#call) },
+                _ => call,
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    // The shims are nested functions, which can't use the generics of the enum or the trait
+    let body = if config.dispatch == Dispatch::Table
+        && wrapper_ident.is_none()
+        && enum_def.generics.params.is_empty()
+        && !generic_trait
+        && table::is_supported(&sig, &method.attrs)
+    {
+        table::dispatch(&sig, enum_ident, &inner, variants, &calls)
+    } else {
+        let arms = variants.iter().zip(&calls).map(|((v, _, attrs), call)| {
+            let variant_attrs = attrs.iter().filter(|a| is_attr_allowed(a, false));
+            quote! { #(#variant_attrs)* #enum_ident::#v(#inner) => #call, }
+        });
+        quote! { match self { #(#arms)* } }
+    };
+
+    let body = match config.tracing {
+        true => trace::instrument(method, &sig, &args, enum_ident, variants, body)?,
+        false => {
+            trace::check_unused(method)?;
+            body
+        }
+    };
+
+    let mut codegen_attrs = codegen_attrs(method, config, inherent.is_some(), is_async)?;
+    // Closures passed to `override_with` are called right where they're declared
+    if overrides.iter().any(|o| o.method == *method_ident) {
+        codegen_attrs.extend(quote! { #[allow(clippy::redundant_closure_call)] });
+    }
+
+    Ok((
+        quote! { #(#attrs)* #codegen_attrs #vis #sig { #body } },
+        wrapper,
+    ))
+}
+
+/// Collect the attributes that affect code generation of a forwarding method.
+///
+/// `#[must_use]` and `#[deprecated]` are only copied to inherent methods, since the trait
+/// declaration already carries them and they are not allowed on trait impls.
+fn codegen_attrs(
+    method: &syn::TraitItemFn,
+    config: &Configure,
+    inherent: bool,
+    is_async: bool,
+) -> Result<TokenStream> {
+    let mut attrs = TokenStream::new();
+
+    // `#[disponent::inline(...)]` takes precedence over `#[inline(...)]` and `configure(inline)`
+    let inline = match crate::helper_attr(&method.attrs, "inline")
+        .or_else(|| method.attrs.iter().find(|a| a.path().is_ident("inline")))
+    {
+        Some(attr) => Some(Inline::from_attr(attr)?),
+        None => config.inline,
+    };
+    inline.to_tokens(&mut attrs);
+
+    for attr in &method.attrs {
+        let Some(ident) = attr.path().get_ident() else {
+            continue;
+        };
+
+        match ident.to_string().as_str() {
+            "cold" if inline == Some(Inline::Always) => {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "`#[cold]` conflicts with `inline(always)` on the generated method",
+                ));
+            }
+            "track_caller" if is_async => {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "`#[track_caller]` has no effect on async methods",
+                ));
+            }
+            "cold" | "track_caller" => attr.to_tokens(&mut attrs),
+            "must_use" if inherent => attr.to_tokens(&mut attrs),
+            "deprecated" => {
+                // The forwarder itself calls the deprecated method
+                attrs.extend(quote! { #[allow(deprecated)] });
+                if inherent {
+                    attr.to_tokens(&mut attrs);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(attrs)
 }
 
 fn generic_param_name(p: &syn::GenericParam) -> &syn::Ident {
@@ -231,7 +716,7 @@ fn generic_param_name(p: &syn::GenericParam) -> &syn::Ident {
     }
 }
 
-fn is_attr_allowed(attr: &syn::Attribute, include_doc: bool) -> bool {
+pub(crate) fn is_attr_allowed(attr: &syn::Attribute, include_doc: bool) -> bool {
     let allowed = if include_doc {
         &["cfg", "cfg_attr", "doc"] as &[_]
     } else {
@@ -243,7 +728,7 @@ fn is_attr_allowed(attr: &syn::Attribute, include_doc: bool) -> bool {
         .is_some_and(|s| allowed.contains(&s.ident.to_string().as_str()))
 }
 
-fn extract_future_output(output: &syn::ReturnType) -> (bool, syn::ReturnType) {
+pub(crate) fn extract_future_output(output: &syn::ReturnType) -> (bool, syn::ReturnType) {
     let syn::ReturnType::Type(_, ty) = output else {
         return (false, output.clone());
     };
@@ -301,6 +786,55 @@ fn build_where_clause(
     Some(combined)
 }
 
+/// The variants that hold their type directly, as opposed to a `#[disponent::dynamic]` trait
+/// object, which implements the trait through a pointer.
+fn static_variants<'a>(
+    variants: &[(&'a syn::Ident, &'a syn::Type, &'a Vec<syn::Attribute>)],
+) -> Vec<(&'a syn::Ident, &'a syn::Type, &'a Vec<syn::Attribute>)> {
+    variants
+        .iter()
+        .filter(|(_, _, attrs)| !dynamic::is_dynamic(attrs))
+        .copied()
+        .collect()
+}
+
+/// The bounds of `where Self: ...` predicates, except `Sized`, which the enum and every variant
+/// satisfy anyway.
+fn self_bounds(where_clause: Option<&syn::WhereClause>) -> Vec<&syn::TypeParamBound> {
+    where_clause
+        .into_iter()
+        .flat_map(|w| &w.predicates)
+        .filter_map(|predicate| match predicate {
+            syn::WherePredicate::Type(p) if p.lifetimes.is_none() && is_self(&p.bounded_ty) => {
+                Some(&p.bounds)
+            }
+            _ => None,
+        })
+        .flatten()
+        .filter(|bound| !is_sized(bound))
+        .collect()
+}
+
+fn respan(tokens: TokenStream, span: proc_macro2::Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let proc_macro2::TokenTree::Group(group) = &token {
+                let mut respanned =
+                    proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = respanned.into();
+            }
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
+
+fn is_sized(bound: &syn::TypeParamBound) -> bool {
+    matches!(bound, syn::TypeParamBound::Trait(t) if t.path.segments.last().is_some_and(|s| s.ident == "Sized"))
+}
+
 fn is_wrapped_self(ty: &syn::Type) -> bool {
     let syn::Type::Path(p) = ty else { return false };
     let (Some(segment), true) = (p.path.segments.last(), p.path.segments.len() == 1) else {
@@ -318,25 +852,36 @@ fn is_wrapped_self(ty: &syn::Type) -> bool {
     inner.path.segments.len() == 1 && inner.path.segments[0].ident == "Self"
 }
 
-fn replace_self(ty: &mut syn::Type, ident: &syn::Ident) {
-    let syn::Type::Path(p) = ty else { return };
-    if p.path.segments.len() == 1 && p.path.segments[0].ident == "Self" {
-        *ty = syn::Type::Path(syn::TypePath {
-            qself: None,
-            path: ident.clone().into(),
-        });
-        return;
-    }
+fn is_self(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(p) if p.qself.is_none() && p.path.is_ident("Self"))
+}
 
-    for seg in &mut p.path.segments {
-        if let syn::PathArguments::AngleBracketed(args) = &mut seg.arguments {
-            for arg in &mut args.args {
-                match arg {
-                    syn::GenericArgument::Type(t) => replace_self(t, ident),
-                    syn::GenericArgument::AssocType(at) => replace_self(&mut at.ty, ident),
-                    _ => {}
-                }
-            }
+/// Replaces `Self` with the enum type, collecting where it was found.
+///
+/// `Self::Assoc` projections are left as is and collected, since they can only be forwarded in
+/// the positions that are skipped by the caller.
+struct ReplaceSelf {
+    enum_ty: syn::Type,
+    found: Vec<proc_macro2::Span>,
+    projections: Vec<syn::Path>,
+}
+
+impl VisitMut for ReplaceSelf {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if is_self(ty) {
+            self.found.push(ty.span());
+            *ty = self.enum_ty.clone();
+            return;
         }
+
+        if let syn::Type::Path(p) = ty
+            && p.qself.is_none()
+            && p.path.segments.len() > 1
+            && p.path.segments[0].ident == "Self"
+        {
+            self.projections.push(p.path.clone());
+        }
+
+        syn::visit_mut::visit_type_mut(self, ty);
     }
 }
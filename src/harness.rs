@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{Result, ext::IdentExt, spanned::Spanned};
+
+/// Generate a `foo_or_bar_tests!` macro that expands each test function into a module with
+/// one `#[test]` per variant.
+///
+/// Variants are constructed with `Default`, or with the expression in `#[disponent::test_with(...)]`.
+pub fn test_harness(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
+    if !enum_def.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            enum_def.generics.span(),
+            "`test_harness` is not supported for generic enums",
+        ));
+    }
+
+    let enum_ident = &enum_def.ident;
+    let macro_ident = format_ident!("{}_tests", to_snake_case(enum_ident));
+
+    let mut tests = Vec::new();
+    for variant in &enum_def.variants {
+        let syn::Fields::Unnamed(fields) = &variant.fields else {
+            continue;
+        };
+        let Some(field) = fields.unnamed.first() else {
+            continue;
+        };
+        let ty = &field.ty;
+        let ident = &variant.ident;
+        let test_ident = format_ident!("{}", to_snake_case(ident));
+        let cfg_attrs = variant
+            .attrs
+            .iter()
+            .filter(|a| crate::forward::is_attr_allowed(a, false));
+
+        let value = match crate::helper_attr(&variant.attrs, "test_with") {
+            Some(attr) => attr.parse_args::<syn::Expr>()?.into_token_stream(),
+            None => quote! { <#ty as ::core::default::Default>::default() },
+        };
+
+        tests.push(quote! {
+            #(#cfg_attrs)*
+            #[test]
+            $(#[$meta])*
+            fn #test_ident() {
+                let $value: #enum_ident = #enum_ident::#ident(#value);
+                $body
+            }
+        });
+    }
+
+    let doc = format!(
+        "Expand each `fn name(value) {{ ... }}` into a module `name` with one test per variant of `{enum_ident}`."
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(unused_macros)]
+        macro_rules! #macro_ident {
+            ($($(#[$meta:meta])* fn $name:ident($value:pat) $body:block)*) => {
+                $(
+                    mod $name {
+                        #[allow(unused_imports)]
+                        use super::*;
+
+                        #(#tests)*
+                    }
+                )*
+            };
+        }
+    })
+}
+
+/// Convert a name like `FooOrBar` to `foo_or_bar`, keeping acronyms together (`HTTPCache` to
+/// `http_cache`).
+pub fn to_snake_case(ident: &syn::Ident) -> String {
+    let chars: Vec<char> = ident.unraw().to_string().chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+            // Start a new word after a lowercase letter or digit, or at the last capital of an acronym
+            let boundary = match prev {
+                Some(prev) if prev.is_uppercase() => next.is_some_and(|n| n.is_lowercase()),
+                Some(prev) => prev != '_',
+                None => false,
+            };
+            if boundary {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
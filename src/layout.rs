@@ -0,0 +1,136 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{Result, ext::IdentExt, spanned::Spanned};
+
+use crate::SizeLint;
+
+/// Fail compilation if a variant is larger than `max` bytes, or more than `ratio` times
+/// larger than the smallest variant.
+pub fn size_lint(enum_def: &syn::ItemEnum, config: &SizeLint) -> Result<TokenStream> {
+    let variants = non_generic_variants(enum_def, "size_lint")?;
+    let enum_name = enum_def.ident.unraw();
+
+    // `#[cfg]`'d out variants don't count towards the smallest variant
+    let sizes = variants.iter().map(|(_, ty, attrs)| {
+        quote! {
+            #(#attrs)*
+            {
+                let size = ::core::mem::size_of::<#ty>();
+                if size < smallest {
+                    smallest = size;
+                }
+            }
+        }
+    });
+    let smallest = quote! {
+        let mut smallest = usize::MAX;
+        #(#sizes)*
+        if smallest == 0 {
+            smallest = 1;
+        }
+    };
+
+    let asserts = variants.iter().map(|(ident, ty, attrs)| {
+        let ident = ident.unraw();
+        let max = config.max.as_ref().map(|max| {
+            let msg = format!(
+                "variant `{enum_name}::{ident}` is larger than {max} bytes, consider boxing its field"
+            );
+            quote_spanned! {ty.span()=>
+                ::core::assert!(::core::mem::size_of::<#ty>() <= #max, #msg);
+            }
+        });
+        let ratio = config.ratio.as_ref().map(|ratio| {
+            let msg = format!(
+                "variant `{enum_name}::{ident}` is more than {ratio} times larger than the smallest variant, consider boxing its field"
+            );
+            quote_spanned! {ty.span()=>
+                #smallest
+                ::core::assert!(::core::mem::size_of::<#ty>() <= smallest * #ratio, #msg);
+            }
+        });
+
+        quote! {
+            #(#attrs)*
+            const _: () = {
+                #max
+                #ratio
+            };
+        }
+    });
+
+    Ok(quote! { #(#asserts)* })
+}
+
+/// Generate a `LAYOUT` constant listing the name, size and alignment of every variant's field.
+pub fn impl_layout(enum_def: &syn::ItemEnum) -> Result<TokenStream> {
+    let variants = non_generic_variants(enum_def, "layout")?;
+    let enum_ident = &enum_def.ident;
+    let vis = &enum_def.vis;
+
+    let entries = variants.iter().map(|(ident, ty, attrs)| {
+        let name = ident.unraw().to_string();
+        quote! {
+            #(#attrs)*
+            {
+                items[i] = (#name, ::core::mem::size_of::<#ty>(), ::core::mem::align_of::<#ty>());
+                i += 1;
+            }
+        }
+    });
+    let counts = variants
+        .iter()
+        .map(|(_, _, attrs)| quote! { #(#attrs)* { len += 1; } });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #enum_ident {
+            /// The name, size and alignment in bytes of each variant's field.
+            #vis const LAYOUT: &'static [(&'static str, usize, usize)] = {
+                const LEN: usize = {
+                    let mut len = 0;
+                    #(#counts)*
+                    len
+                };
+                const ITEMS: [(&str, usize, usize); LEN] = {
+                    let mut items = [("", 0, 0); LEN];
+                    let mut i = 0;
+                    #(#entries)*
+                    let _ = i;
+                    items
+                };
+                &ITEMS
+            };
+        }
+    })
+}
+
+/// Variants with their `#[cfg]` attributes, rejecting generic enums since their sizes
+/// are only known after monomorphization.
+fn non_generic_variants<'a>(
+    enum_def: &'a syn::ItemEnum,
+    option: &str,
+) -> Result<Vec<(&'a syn::Ident, &'a syn::Type, Vec<&'a syn::Attribute>)>> {
+    if !enum_def.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            enum_def.generics.span(),
+            format!("`{option}` is not supported for generic enums"),
+        ));
+    }
+
+    Ok(enum_def
+        .variants
+        .iter()
+        .filter_map(|v| match &v.fields {
+            syn::Fields::Unnamed(f) if f.unnamed.len() == 1 => Some((
+                &v.ident,
+                &f.unnamed.first()?.ty,
+                v.attrs
+                    .iter()
+                    .filter(|a| crate::forward::is_attr_allowed(a, false))
+                    .collect(),
+            )),
+            _ => None,
+        })
+        .collect())
+}
@@ -31,21 +31,98 @@
 //!
 //! Use [`#[disponent::configure(...)]`][configure] on the enum with:
 //! - `inherent`: Generate inherent methods (vs trait impl)
-//! - `inline`: Add `#[inline]` to methods
+//! - `inline`: Add `#[inline]` (or `inline(always)`/`inline(never)`) to methods
 //! - `from`: Generate `From<T> for Enum` impls
 //! - `try_into`: Generate `TryInto<T> for Enum` impls
+//! - `dispatch = "table"`: Dispatch through a table of function pointers instead of a `match`
+//! - `size_lint(max = .., ratio = ..)`: Fail compilation if a variant is too large
+//! - `layout`: Generate a `LAYOUT` constant with the size and alignment of each variant
+//! - `hook = Type`: Call `Type::before` and `Type::after` around every forwarded call
+//! - `async_hook = Type`: Await `Type::before` and `Type::after` around async methods instead
+//! - `tracing`: Wrap every generated method in a `tracing` span (requires the `tracing` feature)
+//! - `stats`: Count calls per variant and method, see `Enum::dispatch_stats()`
+//! - `unify_errors`: Combine the `Self::Error` types of all variants into one error enum
+//! - `serde(tag = "..", content = "..")`: Derive `Serialize` and `Deserialize` for the enum
+//!   (requires the `serde` feature)
+//! - `factory`: Generate `Enum::from_name(name, args)` to construct variants by name
+//! - `arbitrary`, `proptest`: Implement `Arbitrary` for fuzzing and property tests (require the
+//!   `arbitrary` and `proptest` features)
+//! - `test_harness`: Generate a macro that runs a test against every variant
+//! - `export_macro(module = ..)`: Generate an exported macro to match on the variants and
+//!   forward other traits from other crates
+//!
+//! ## Method Attributes
+//!
+//! `#[cold]`, `#[track_caller]` and `#[inline(...)]` are copied from trait methods to the
+//! generated methods, `#[must_use]` and `#[deprecated]` only to inherent methods.
+//! `#[disponent::inline(...)]` overrides `configure(inline)` for a single method.
+//!
+//! ## Overrides
+//!
+//! `#[disponent::override_with(Variant => expr)]` on a trait method, or
+//! `#[disponent::override_with(method = expr)]` on a variant, calls `expr` with the
+//! variant's inner value and the method's arguments instead of the trait method.
+//!
+//! ## Skipping Methods
+//!
+//! `#[disponent::skip]` on a default method runs the default body on the enum itself
+//! instead of forwarding it to each variant.
+//!
+//! ## Nested Enums
+//!
+//! `#[disponent::nested(Memory(Memory), Disk(Disk))]` on a variant holding another dispatch enum
+//! lists its variants, to generate transitive `From`/`TryInto` impls with `from`/`try_into`
+//! and `as_memory()`/`as_memory_mut()` accessors.
+//!
+//! ## Dynamic Variants
+//!
+//! A `#[disponent::dynamic]` variant like `Custom(Box<dyn Trait>)` is dispatched through the
+//! trait object. Methods that can't be called on `dyn Trait` use the variant's
+//! `#[disponent::override_with(...)]`, or panic with `#[disponent::dynamic(fallback = panic)]`.
+//!
+//! ## Unsafe Traits
+//!
+//! `unsafe trait`s get an `unsafe impl`, and `unsafe fn` methods forward to the variant's
+//! method in an `unsafe` block with the same safety contract.
+//!
+//! ## Supertraits
+//!
+//! Supertraits of the trait that are declared in the same block are forwarded to the variants as
+//! well, as are supertraits mirrored with `#[disponent::supertrait(path::to::Trait { fn ...; })]`
+//! on the trait. Other supertraits have to be implemented by the enum itself.
+//!
+//! ## Generic Associated Types
+//!
+//! Associated types with lifetime parameters, like `type Guard<'a> where Self: 'a;`, are
+//! forwarded through a companion enum (`FooOrBarGuard<'a>`) holding each variant's type.
+//! Methods returning `Self::Guard<'_>` wrap the variant's value in it.
 //!
 //! ## Remote Traits
 //!
 //! Use [`#[disponent::remote(...)]`][remote] on the trait to implement a trait defined elsewhere.
 
+mod arbitrary;
 mod convert;
+mod dynamic;
+mod errors;
+mod export;
+mod factory;
 mod forward;
+mod harness;
+mod layout;
+mod overrides;
+mod serde;
+mod stats;
+mod supertraits;
+mod table;
+mod trace;
+mod wrapper;
 
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{
     Result,
+    ext::IdentExt,
     parse::{Parse, ParseStream},
     parse_macro_input,
     spanned::Spanned,
@@ -56,12 +133,117 @@ enum InherentConfig {
     Explicit(syn::Visibility),
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum Inline {
+    Hint,
+    Always,
+    Never,
+}
+
+/// Parses the `always` or `never` in `inline(...)`.
+impl Parse for Inline {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "always" => Ok(Inline::Always),
+            "never" => Ok(Inline::Never),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "Unknown inline option, expected `always` or `never`",
+            )),
+        }
+    }
+}
+
+impl Inline {
+    /// Parse `#[inline]`, `#[inline(always)]` or `#[inline(never)]` (or their `disponent::` helpers).
+    fn from_attr(attr: &syn::Attribute) -> Result<Self> {
+        match &attr.meta {
+            syn::Meta::Path(_) => Ok(Inline::Hint),
+            syn::Meta::List(_) => attr.parse_args(),
+            syn::Meta::NameValue(_) => Err(syn::Error::new(
+                attr.span(),
+                "Expected `inline`, `inline(always)` or `inline(never)`",
+            )),
+        }
+    }
+}
+
+impl ToTokens for Inline {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Inline::Hint => quote::quote! { #[inline] },
+            Inline::Always => quote::quote! { #[inline(always)] },
+            Inline::Never => quote::quote! { #[inline(never)] },
+        });
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum Dispatch {
+    #[default]
+    Match,
+    Table,
+}
+
+struct SizeLint {
+    max: Option<syn::LitInt>,
+    ratio: Option<syn::LitInt>,
+}
+
+impl Parse for SizeLint {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut max = None;
+        let mut ratio = None;
+
+        while !input.is_empty() {
+            let ident: syn::Ident = input.parse()?;
+            input.parse::<syn::Token![=]>()?;
+            let value: syn::LitInt = input.parse()?;
+            value.base10_parse::<usize>()?;
+            match ident.to_string().as_str() {
+                "max" => max = Some(value),
+                "ratio" => ratio = Some(value),
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "Unknown size_lint option, expected `max` or `ratio`",
+                    ));
+                }
+            }
+            if input.peek(syn::Token![,]) {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        if max.is_none() && ratio.is_none() {
+            return Err(input.error("Expected `max = <bytes>` and/or `ratio = <factor>`"));
+        }
+
+        Ok(SizeLint { max, ratio })
+    }
+}
+
 #[derive(Default)]
 struct Configure {
     inherent: Option<InherentConfig>,
     from: bool,
     try_into: bool,
-    inline: bool,
+    inline: Option<Inline>,
+    dispatch: Dispatch,
+    size_lint: Option<SizeLint>,
+    layout: bool,
+    hook: Option<syn::Type>,
+    async_hook: Option<syn::Type>,
+    tracing: bool,
+    stats: bool,
+    unify_errors: bool,
+    serde: Option<serde::SerdeConfig>,
+    factory: bool,
+    arbitrary: bool,
+    proptest: bool,
+    test_harness: bool,
+    export_macro: Option<export::ExportConfig>,
 }
 
 struct Remote {
@@ -81,7 +263,21 @@ impl Parse for Configure {
         let mut inherent: Option<InherentConfig> = None;
         let mut from = false;
         let mut try_into = false;
-        let mut inline = false;
+        let mut inline = None;
+        let mut dispatch = Dispatch::Match;
+        let mut size_lint = None;
+        let mut layout = false;
+        let mut hook = None;
+        let mut async_hook = None;
+        let mut tracing = false;
+        let mut stats = false;
+        let mut unify_errors = false;
+        let mut serde = None;
+        let mut factory = false;
+        let mut arbitrary = false;
+        let mut proptest = false;
+        let mut test_harness = false;
+        let mut export_macro = None;
 
         while !input.is_empty() {
             let ident: syn::Ident = input.parse()?;
@@ -98,7 +294,93 @@ impl Parse for Configure {
                 }
                 "from" => from = true,
                 "try_into" => try_into = true,
-                "inline" => inline = true,
+                "inline" => {
+                    if input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in input);
+                        inline = Some(content.parse()?);
+                    } else {
+                        inline = Some(Inline::Hint);
+                    }
+                }
+                "dispatch" => {
+                    input.parse::<syn::Token![=]>()?;
+                    let value: syn::LitStr = input.parse()?;
+                    dispatch = match value.value().as_str() {
+                        "match" => Dispatch::Match,
+                        "table" => Dispatch::Table,
+                        _ => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "Unknown dispatch mode, expected `\"match\"` or `\"table\"`",
+                            ));
+                        }
+                    };
+                }
+                "size_lint" => {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    size_lint = Some(content.parse()?);
+                }
+                "layout" => layout = true,
+                "hook" => {
+                    input.parse::<syn::Token![=]>()?;
+                    hook = Some(input.parse()?);
+                }
+                "async_hook" => {
+                    input.parse::<syn::Token![=]>()?;
+                    async_hook = Some(input.parse()?);
+                }
+                "stats" => stats = true,
+                "unify_errors" => unify_errors = true,
+                "factory" => factory = true,
+                "test_harness" => test_harness = true,
+                "export_macro" => {
+                    if input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in input);
+                        export_macro = Some(content.parse()?);
+                    } else {
+                        export_macro = Some(export::ExportConfig::default());
+                    }
+                }
+                // Features only gate the options, since another crate may have enabled them
+                "arbitrary" if cfg!(feature = "arbitrary") => arbitrary = true,
+                "arbitrary" => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "`arbitrary` requires the `arbitrary` feature of `disponent`",
+                    ));
+                }
+                "proptest" if cfg!(feature = "proptest") => proptest = true,
+                "proptest" => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "`proptest` requires the `proptest` feature of `disponent`",
+                    ));
+                }
+                "serde" if cfg!(feature = "serde") => {
+                    if input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in input);
+                        serde = Some(content.parse()?);
+                    } else {
+                        serde = Some(serde::SerdeConfig::default());
+                    }
+                }
+                "serde" => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "`serde` requires the `serde` feature of `disponent`",
+                    ));
+                }
+                "tracing" if cfg!(feature = "tracing") => tracing = true,
+                "tracing" => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "`tracing` requires the `tracing` feature of `disponent`",
+                    ));
+                }
                 _ => {
                     return Err(syn::Error::new(
                         ident.span(),
@@ -116,10 +398,93 @@ impl Parse for Configure {
             from,
             try_into,
             inline,
+            dispatch,
+            size_lint,
+            layout,
+            hook,
+            async_hook,
+            tracing,
+            stats,
+            unify_errors,
+            serde,
+            factory,
+            arbitrary,
+            proptest,
+            test_harness,
+            export_macro,
         })
     }
 }
 
+/// Attributes like `#[disponent::inline]` that only guide code generation.
+///
+/// These are not macros, so they are removed before the trait and enum are emitted.
+const HELPER_ATTRIBUTES: &[&str] = &[
+    "dynamic",
+    "factory",
+    "inline",
+    "name",
+    "nested",
+    "override_with",
+    "record",
+    "skip",
+    "supertrait",
+    "test_with",
+    "weight",
+];
+
+/// Find the helper attribute `#[disponent::<name>]`.
+fn helper_attr<'a>(attrs: &'a [syn::Attribute], name: &str) -> Option<&'a syn::Attribute> {
+    attrs.iter().find(|attr| is_helper_attr(attr, name))
+}
+
+fn is_helper_attr(attr: &syn::Attribute, name: &str) -> bool {
+    let segments = &attr.path().segments;
+    segments.len() == 2 && segments[0].ident == "disponent" && segments[1].ident == name
+}
+
+/// The name of a variant for serialization and lookups, from `#[disponent::name("...")]`
+/// or the variant's ident.
+fn variant_name(variant: &syn::Variant) -> Result<syn::LitStr> {
+    match helper_attr(&variant.attrs, "name") {
+        Some(attr) => attr.parse_args(),
+        None => Ok(syn::LitStr::new(
+            &variant.ident.unraw().to_string(),
+            variant.ident.span(),
+        )),
+    }
+}
+
+fn strip_helper_attrs(items: &mut [syn::Item]) {
+    let retain = |attrs: &mut Vec<syn::Attribute>| {
+        attrs.retain(|attr| {
+            !HELPER_ATTRIBUTES
+                .iter()
+                .any(|name| is_helper_attr(attr, name))
+        })
+    };
+
+    for item in items {
+        match item {
+            syn::Item::Trait(t) => {
+                retain(&mut t.attrs);
+                for item in &mut t.items {
+                    if let syn::TraitItem::Fn(m) = item {
+                        retain(&mut m.attrs);
+                    }
+                }
+            }
+            syn::Item::Enum(e) => {
+                retain(&mut e.attrs);
+                for variant in &mut e.variants {
+                    retain(&mut variant.attrs);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 struct Disponent(TokenStream);
 
 impl Parse for Disponent {
@@ -127,17 +492,14 @@ impl Parse for Disponent {
         let input: TokenStream = input.parse()?;
         let out = input.clone();
 
-        let items = match syn::parse2::<syn::File>(input) {
-            Ok(f) => f.items,
+        let mut file = match syn::parse2::<syn::File>(input) {
+            Ok(f) => f,
             Err(_) => return Ok(Disponent(out)),
         };
+        let items = &file.items;
 
-        let trait_def = items
-            .iter()
-            .find_map(|item| match item {
-                syn::Item::Trait(t) => Some(t.clone()),
-                _ => None,
-            })
+        let trait_def = supertraits::find_primary(items)
+            .cloned()
             .ok_or_else(|| syn::Error::new(out.span(), "Missing trait definition"))?;
 
         let enum_def = items
@@ -174,13 +536,10 @@ impl Parse for Disponent {
             .transpose()?
             .map(|remote| remote.path);
 
-        let forward_to_variant = forward::forward_to_variant(
-            config.inherent.as_ref(),
-            config.inline,
-            &enum_def,
-            &trait_def,
-            remote_path.as_ref(),
-        )?;
+        let forward_to_variant =
+            forward::forward_to_variant(&config, &enum_def, &trait_def, remote_path.as_ref())?;
+
+        let supertraits = supertraits::forward_supertraits(&config, &enum_def, &trait_def, items)?;
 
         let from_impl = if config.from {
             convert::impl_from(&enum_def)?
@@ -194,11 +553,68 @@ impl Parse for Disponent {
             TokenStream::new()
         };
 
+        let nested_accessors = convert::impl_nested_accessors(&enum_def)?;
+        let dynamic_from = dynamic::impl_from(&enum_def)?;
+
+        let size_lint = match &config.size_lint {
+            Some(size_lint) => layout::size_lint(&enum_def, size_lint)?,
+            None => TokenStream::new(),
+        };
+
+        let layout_impl = if config.layout {
+            layout::impl_layout(&enum_def)?
+        } else {
+            TokenStream::new()
+        };
+
+        let factory_impl = if config.factory {
+            factory::impl_factory(&enum_def)?
+        } else {
+            TokenStream::new()
+        };
+
+        let arbitrary_impl = if config.arbitrary || config.proptest {
+            arbitrary::impl_arbitrary(&enum_def, config.arbitrary, config.proptest)?
+        } else {
+            TokenStream::new()
+        };
+
+        let test_harness = if config.test_harness {
+            harness::test_harness(&enum_def)?
+        } else {
+            TokenStream::new()
+        };
+
+        let export_macro = match &config.export_macro {
+            Some(export) => export::export_macro(&enum_def, export)?,
+            None => TokenStream::new(),
+        };
+
+        if let Some(serde) = &config.serde
+            && let Some(enum_def) = file.items.iter_mut().find_map(|item| match item {
+                syn::Item::Enum(e) => Some(e),
+                _ => None,
+            })
+        {
+            serde::derive(enum_def, serde)?;
+        }
+
+        strip_helper_attrs(&mut file.items);
+
         let definition = quote::quote! {
-            #out
+            #file
             #forward_to_variant
+            #supertraits
             #from_impl
             #try_into_impl
+            #nested_accessors
+            #dynamic_from
+            #size_lint
+            #layout_impl
+            #factory_impl
+            #arbitrary_impl
+            #test_harness
+            #export_macro
         };
 
         Ok(Disponent(definition))
@@ -250,9 +666,56 @@ pub fn declare(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// Apply to the enum within [`declare!`] with any combination of:
 /// - `inherent`: Generate inherent methods on the enum with the same visibility as the enum
 /// - `inherent(<visibility>)`: Generate inherent methods with explicit visibility (e.g., `inherent(pub)`, `inherent(pub(crate))`)
-/// - `inline`: Add `#[inline]` to all generated methods
+/// - `inline`, `inline(always)` or `inline(never)`: Add `#[inline(...)]` to all generated methods.
+///   Use `#[disponent::inline(...)]` on a trait method to override this for a single method.
 /// - `from`: Generate `From` impls for each variant
 /// - `try_into`: Generate `TryInto` impls for each variant
+/// - `dispatch = "match"` (default) or `dispatch = "table"`: With `"table"`, methods index a static
+///   array of function pointers by the variant instead of matching on it. Async methods, methods
+///   with type parameters, `impl Trait` or `#[track_caller]`, and generic enums and traits always
+///   use a `match`.
+/// - `size_lint(max = <bytes>, ratio = <factor>)`: Fail compilation if a variant's field is larger
+///   than `max` bytes, or more than `ratio` times larger than the smallest variant's field
+/// - `layout`: Generate a `LAYOUT: &[(&str, usize, usize)]` constant with the name, size and
+///   alignment of each variant's field
+/// - `hook = Type`: Call `Type::before(variant, method)` and `Type::after(variant, method)` (both
+///   taking `&'static str`) around every forwarded call. For async methods, they run when the
+///   future is polled, before and after awaiting the variant's future.
+/// - `async_hook = Type`: Await `Type::before(variant, method)` and `Type::after(variant, method)`,
+///   which are `async fn`s, around async methods instead of calling the `hook`.
+/// - `tracing`: Wrap every generated method in a `tracing` span named `Enum::method`, with the
+///   active variant as a field. Requires the `tracing` feature, and the `tracing` crate as a
+///   dependency of the calling crate. Arguments are only recorded (with their `Debug` impl) when
+///   listed in `#[disponent::record(arg, ...)]` on the method.
+/// - `stats`: Count calls per variant and method in static `AtomicU64` counters, and generate
+///   `Enum::dispatch_stats()` (an iterator of `(variant, method, calls)`) and `Enum::reset_stats()`.
+///   Counters are shared between all instantiations of a generic enum.
+/// - `unify_errors`: Allow associated types used as errors in `Result<T, Self::Error>` return
+///   types, and generate an enum like `FooOrBarError` that holds any variant's error. Each arm's
+///   error is wrapped with `.map_err(FooOrBarError::Variant)`, and the enum implements `Debug`,
+///   `Display`, `Error` and `From` for each variant's error. Variants of the same type share their
+///   error and get no `From` impl, other variants need distinct error types.
+/// - `serde`, `serde(tag = "..")`, `serde(tag = "..", content = "..")`: Derive `Serialize` and
+///   `Deserialize` for the enum, externally, internally or adjacently tagged by the variant name
+///   or `#[disponent::name("...")]`. Requires the `serde` feature, and `serde` with the `derive`
+///   feature as a dependency of the calling crate.
+/// - `factory`: Generate `Enum::from_name(name, args)` and `Enum::names()` to construct variants
+///   by their name or `#[disponent::name("...")]`. Variants are constructed with `Default`, or
+///   from `args` with `#[disponent::factory(FromStr)]` or `#[disponent::factory(path::to::ctor)]`.
+///   Errors are reported as `EnumFactoryError::UnknownVariant` or `EnumFactoryError::Invalid`.
+/// - `arbitrary`, `proptest`: Implement `arbitrary::Arbitrary` and
+///   `proptest::arbitrary::Arbitrary` respectively by picking a variant, weighted by
+///   `#[disponent::weight(n)]` (1 by default), and generating its field. Each option requires the
+///   feature of the same name.
+/// - `test_harness`: Generate a `foo_or_bar_tests!` macro that turns each `fn name(value) { ... }`
+///   into a module `name` with one `#[test]` per variant, such as `name::foo`, since `macro_rules!`
+///   can't join identifiers into `name_foo`. Variants are constructed with `Default`, or with the
+///   expression in `#[disponent::test_with(...)]`.
+/// - `export_macro`, `export_macro(module = crate::path)`: Generate a `#[macro_export]`
+///   `foo_or_bar_dispatch!` macro. `foo_or_bar_dispatch!(value, inner => expr)` matches on the
+///   variants, and `foo_or_bar_dispatch!(impl Trait { fn method(&self, arg: Type) -> Ret; })`
+///   implements `Trait` for the enum by forwarding each method. `module` is the path of the
+///   module declaring the enum, which defaults to the crate root.
 ///
 /// # Example
 ///
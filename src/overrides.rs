@@ -0,0 +1,127 @@
+use syn::{
+    Result,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// A replacement for a single variant's implementation of a method.
+pub struct Override {
+    pub variant: syn::Ident,
+    pub method: syn::Ident,
+    pub with: syn::Expr,
+}
+
+/// `#[disponent::override_with(Variant => expr, ...)]` on a trait method.
+struct MethodOverride {
+    variant: syn::Ident,
+    with: syn::Expr,
+}
+
+impl Parse for MethodOverride {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let variant = input.parse()?;
+        input.parse::<syn::Token![=>]>()?;
+        Ok(MethodOverride {
+            variant,
+            with: input.parse()?,
+        })
+    }
+}
+
+/// `#[disponent::override_with(method = expr, ...)]` on a variant.
+struct VariantOverride {
+    method: syn::Ident,
+    with: syn::Expr,
+}
+
+impl Parse for VariantOverride {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let method = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        Ok(VariantOverride {
+            method,
+            with: input.parse()?,
+        })
+    }
+}
+
+/// Collect the overrides from all trait methods and variants.
+pub fn collect(
+    trait_def: &syn::ItemTrait,
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+) -> Result<Vec<Override>> {
+    let methods: Vec<_> = trait_def
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::TraitItem::Fn(m) => Some(m),
+            _ => None,
+        })
+        .collect();
+
+    let mut overrides: Vec<Override> = Vec::new();
+    let mut push = |o: Override| {
+        if overrides
+            .iter()
+            .any(|other| other.variant == o.variant && other.method == o.method)
+        {
+            return Err(syn::Error::new(
+                o.variant.span(),
+                format!(
+                    "Duplicate override of `{}` for variant `{}`",
+                    o.method, o.variant
+                ),
+            ));
+        }
+        overrides.push(o);
+        Ok(())
+    };
+
+    for method in &methods {
+        for attr in helper_attrs(&method.attrs) {
+            for o in attr
+                .parse_args_with(Punctuated::<MethodOverride, syn::Token![,]>::parse_terminated)?
+            {
+                if !variants.iter().any(|(v, _, _)| **v == o.variant) {
+                    return Err(syn::Error::new(
+                        o.variant.span(),
+                        format!("Unknown variant `{}`", o.variant),
+                    ));
+                }
+                push(Override {
+                    variant: o.variant,
+                    method: method.sig.ident.clone(),
+                    with: o.with,
+                })?;
+            }
+        }
+    }
+
+    for (variant, _, attrs) in variants {
+        for attr in helper_attrs(attrs) {
+            for o in attr
+                .parse_args_with(Punctuated::<VariantOverride, syn::Token![,]>::parse_terminated)?
+            {
+                if !methods.iter().any(|m| m.sig.ident == o.method) {
+                    return Err(syn::Error::new(
+                        o.method.span(),
+                        format!("Unknown method `{}`", o.method),
+                    ));
+                }
+                push(Override {
+                    variant: (*variant).clone(),
+                    method: o.method,
+                    with: o.with,
+                })?;
+            }
+        }
+    }
+
+    Ok(overrides)
+}
+
+fn helper_attrs(attrs: &[syn::Attribute]) -> impl Iterator<Item = &syn::Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| crate::is_helper_attr(attr, "override_with"))
+}
@@ -0,0 +1,71 @@
+use syn::{
+    Result,
+    parse::{Parse, ParseStream},
+    parse_quote,
+};
+
+/// `serde`, `serde(tag = "..")` or `serde(tag = "..", content = "..")` in configure.
+#[derive(Default)]
+pub struct SerdeConfig {
+    tag: Option<syn::LitStr>,
+    content: Option<syn::LitStr>,
+}
+
+impl Parse for SerdeConfig {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut config = SerdeConfig::default();
+
+        while !input.is_empty() {
+            let ident: syn::Ident = input.parse()?;
+            input.parse::<syn::Token![=]>()?;
+            match ident.to_string().as_str() {
+                "tag" => config.tag = Some(input.parse()?),
+                "content" => config.content = Some(input.parse()?),
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "Unknown serde option, expected `tag` or `content`",
+                    ));
+                }
+            }
+            if input.peek(syn::Token![,]) {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        if let (None, Some(content)) = (&config.tag, &config.content) {
+            return Err(syn::Error::new(
+                content.span(),
+                "`content` requires `tag` to be set as well",
+            ));
+        }
+
+        Ok(config)
+    }
+}
+
+/// Derive `Serialize` and `Deserialize` for the enum, tagged by the variant names.
+///
+/// The derives handle `#[cfg]`'d variants and list the valid tags in deserialization errors.
+pub fn derive(enum_def: &mut syn::ItemEnum, config: &SerdeConfig) -> Result<()> {
+    enum_def
+        .attrs
+        .push(parse_quote!(#[derive(::serde::Serialize, ::serde::Deserialize)]));
+
+    match (&config.tag, &config.content) {
+        (Some(tag), Some(content)) => enum_def
+            .attrs
+            .push(parse_quote!(#[serde(tag = #tag, content = #content)])),
+        (Some(tag), None) => enum_def.attrs.push(parse_quote!(#[serde(tag = #tag)])),
+        _ => {}
+    }
+
+    for variant in &mut enum_def.variants {
+        if crate::helper_attr(&variant.attrs, "name").is_some() {
+            let name = crate::variant_name(variant)?;
+            variant.attrs.push(parse_quote!(#[serde(rename = #name)]));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+
+/// Generate the call counters and the `dispatch_stats` and `reset_stats` functions.
+///
+/// Counters live in a single static array with one entry per variant and method, so
+/// incrementing one is a single relaxed atomic add. Variants that are `#[cfg]`'d out keep
+/// their slot, but aren't reported.
+pub fn impl_stats(enum_def: &syn::ItemEnum, methods: &[&syn::Ident]) -> TokenStream {
+    let enum_ident = &enum_def.ident;
+    let vis = &enum_def.vis;
+    let (impl_generics, ty_generics, where_clause) = enum_def.generics.split_for_impl();
+
+    let variant_count = enum_def.variants.len();
+    let method_count = methods.len();
+    let len = variant_count * method_count;
+
+    let variant_names = enum_def.variants.iter().enumerate().map(|(i, v)| {
+        let cfg_attrs = v
+            .attrs
+            .iter()
+            .filter(|a| crate::forward::is_attr_allowed(a, false));
+        let name = v.ident.unraw().to_string();
+        quote! { #(#cfg_attrs)* { variants[#i] = ::core::option::Option::Some(#name); } }
+    });
+    let method_names = methods.iter().map(|m| m.unraw().to_string());
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #[doc(hidden)]
+            #[inline(always)]
+            fn __disponent_stats() -> &'static [::core::sync::atomic::AtomicU64; #len] {
+                static STATS: [::core::sync::atomic::AtomicU64; #len] =
+                    [const { ::core::sync::atomic::AtomicU64::new(0) }; #len];
+                &STATS
+            }
+
+            /// The number of calls to each method per variant since the last reset, as
+            /// `(variant, method, calls)`.
+            #vis fn dispatch_stats() -> impl ::core::iter::Iterator<Item = (&'static str, &'static str, u64)> {
+                const VARIANTS: [::core::option::Option<&str>; #variant_count] = {
+                    let mut variants = [::core::option::Option::None; #variant_count];
+                    #(#variant_names)*
+                    variants
+                };
+                const METHODS: [&str; #method_count] = [#(#method_names),*];
+
+                let stats = Self::__disponent_stats();
+                VARIANTS
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(i, variant)| ::core::option::Option::Some((i, variant?)))
+                    .flat_map(move |(i, variant)| {
+                        METHODS.into_iter().enumerate().map(move |(j, method)| {
+                            let calls = stats[i * #method_count + j].load(::core::sync::atomic::Ordering::Relaxed);
+                            (variant, method, calls)
+                        })
+                    })
+            }
+
+            /// Reset all call counters to zero.
+            #vis fn reset_stats() {
+                for counter in Self::__disponent_stats() {
+                    counter.store(0, ::core::sync::atomic::Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
+
+/// Increment the counter of a variant and method before running `call`.
+pub fn count(
+    enum_def: &syn::ItemEnum,
+    variant: &syn::Ident,
+    method_index: usize,
+    method_count: usize,
+    call: TokenStream,
+) -> TokenStream {
+    let enum_ident = &enum_def.ident;
+    let turbofish = enum_def.generics.split_for_impl().1.as_turbofish();
+    let variant_index = enum_def
+        .variants
+        .iter()
+        .position(|v| v.ident == *variant)
+        .expect("variant belongs to the enum");
+    let index = variant_index * method_count + method_index;
+
+    quote! {{
+        #enum_ident #turbofish::__disponent_stats()[#index]
+            .fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+        #call
+    }}
+}
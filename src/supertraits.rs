@@ -0,0 +1,136 @@
+use proc_macro2::TokenStream;
+use syn::{
+    Result,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{Configure, forward};
+
+/// `#[disponent::supertrait(path::to::Trait { fn ...; })]` on the trait, mirroring a supertrait
+/// that is defined elsewhere.
+struct Mirror {
+    path: syn::Path,
+    items: Vec<syn::TraitItem>,
+}
+
+impl Parse for Mirror {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let path = input.parse()?;
+        let content;
+        syn::braced!(content in input);
+        let mut items = Vec::new();
+        while !content.is_empty() {
+            items.push(content.parse()?);
+        }
+        Ok(Mirror { path, items })
+    }
+}
+
+/// Find the trait to dispatch, which is the first trait that isn't a supertrait of another
+/// trait in the block.
+pub fn find_primary(items: &[syn::Item]) -> Option<&syn::ItemTrait> {
+    let traits: Vec<_> = items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Trait(t) => Some(t),
+            _ => None,
+        })
+        .collect();
+
+    traits
+        .iter()
+        .find(|t| {
+            !traits
+                .iter()
+                .any(|other| trait_bounds(other).any(|b| is_named(&b.path, &t.ident)))
+        })
+        .or(traits.first())
+        .copied()
+}
+
+/// Forward the supertraits of the dispatched trait to the variants, if they are declared in
+/// the same block or mirrored with `#[disponent::supertrait(...)]`.
+///
+/// Other supertraits have to be implemented by the enum itself, which the compiler already
+/// reports at the enum if they aren't.
+pub fn forward_supertraits(
+    config: &Configure,
+    enum_def: &syn::ItemEnum,
+    trait_def: &syn::ItemTrait,
+    items: &[syn::Item],
+) -> Result<TokenStream> {
+    // Inherent methods don't implement the trait, so its supertraits aren't required
+    if config.inherent.is_some() {
+        return Ok(TokenStream::new());
+    }
+
+    let mirrors = trait_def
+        .attrs
+        .iter()
+        .filter(|attr| crate::is_helper_attr(attr, "supertrait"))
+        .map(|attr| attr.parse_args::<Mirror>())
+        .collect::<Result<Vec<_>>>()?;
+
+    // Supertraits only get the options that affect the generated methods
+    let super_config = Configure {
+        inline: config.inline,
+        dispatch: config.dispatch,
+        hook: config.hook.clone(),
+        async_hook: config.async_hook.clone(),
+        tracing: config.tracing,
+        ..Default::default()
+    };
+
+    let mut tokens = TokenStream::new();
+    let mut visited = vec![trait_def.ident.clone()];
+    let mut queue: Vec<&syn::TraitBound> = trait_bounds(trait_def).collect();
+
+    while let Some(bound) = queue.pop() {
+        let Some(name) = bound.path.segments.last().map(|s| &s.ident) else {
+            continue;
+        };
+        if visited.contains(name) {
+            continue;
+        }
+        visited.push(name.clone());
+
+        let declared = items.iter().find_map(|item| match item {
+            syn::Item::Trait(t) if t.ident == *name => Some(t),
+            _ => None,
+        });
+
+        if let Some(declared) = declared {
+            tokens.extend(forward::forward_to_variant(
+                &super_config,
+                enum_def,
+                declared,
+                None,
+            )?);
+            queue.extend(trait_bounds(declared));
+        } else if let Some(mirror) = mirrors.iter().find(|m| is_named(&m.path, name)) {
+            let items = &mirror.items;
+            let mirror_def: syn::ItemTrait = syn::parse_quote! { trait #name { #(#items)* } };
+            tokens.extend(forward::forward_to_variant(
+                &super_config,
+                enum_def,
+                &mirror_def,
+                Some(&mirror.path),
+            )?);
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn trait_bounds(trait_def: &syn::ItemTrait) -> impl Iterator<Item = &syn::TraitBound> {
+    trait_def.supertraits.iter().filter_map(|b| match b {
+        syn::TypeParamBound::Trait(t) if matches!(t.modifier, syn::TraitBoundModifier::None) => {
+            Some(t)
+        }
+        _ => None,
+    })
+}
+
+fn is_named(path: &syn::Path, ident: &syn::Ident) -> bool {
+    path.segments.last().is_some_and(|s| s.ident == *ident)
+}
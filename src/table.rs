@@ -0,0 +1,159 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::visit_mut::VisitMut;
+
+/// Check whether a method can be dispatched through a table of function pointers.
+///
+/// Function pointers can't be generic and can't name opaque types, so async methods,
+/// methods with type or const generics and `impl Trait` arguments use a `match` instead.
+/// The shims would also report their own location to `#[track_caller]` methods.
+pub fn is_supported(sig: &syn::Signature, attrs: &[syn::Attribute]) -> bool {
+    let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first() else {
+        return false;
+    };
+
+    let only_unbounded_lifetimes = sig.generics.params.iter().all(|p| match p {
+        syn::GenericParam::Lifetime(l) => l.bounds.is_empty(),
+        _ => false,
+    });
+
+    let typed_args = sig.inputs.iter().skip(1).filter_map(|a| match a {
+        syn::FnArg::Typed(p) => Some(p.ty.to_token_stream()),
+        _ => None,
+    });
+    let output = sig.output.to_token_stream();
+
+    sig.asyncness.is_none()
+        && sig.variadic.is_none()
+        && !attrs.iter().any(|a| a.path().is_ident("track_caller"))
+        && receiver.colon_token.is_none()
+        && only_unbounded_lifetimes
+        && sig.generics.where_clause.is_none()
+        && typed_args
+            .chain([output])
+            .all(|tokens| !contains_ident(tokens, &["impl", "Self"]))
+}
+
+/// Dispatch through a static array of function pointers indexed by the variant.
+///
+/// Each entry is a shim that unwraps its variant and runs the same call as the `match` arm would.
+pub fn dispatch(
+    sig: &syn::Signature,
+    enum_ident: &syn::Ident,
+    inner: &syn::Ident,
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+    calls: &[TokenStream],
+) -> TokenStream {
+    let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first() else {
+        unreachable!("methods without a receiver are not supported by table dispatch")
+    };
+
+    let mut lifetimes: Vec<_> = sig
+        .generics
+        .lifetimes()
+        .map(|l| &l.lifetime)
+        .cloned()
+        .collect();
+    let mut output = sig.output.clone();
+
+    // Elided output lifetimes refer to `self`, which is just another argument in the shim
+    let this_ty = match &receiver.reference {
+        Some((_, lifetime)) => {
+            let lifetime = lifetime.clone().unwrap_or_else(|| {
+                let lifetime =
+                    syn::Lifetime::new("'__disponent_self", proc_macro2::Span::call_site());
+                lifetimes.push(lifetime.clone());
+                lifetime
+            });
+            ElidedLifetimes(&lifetime).visit_return_type_mut(&mut output);
+            let mutability = &receiver.mutability;
+            quote! { &#lifetime #mutability #enum_ident }
+        }
+        None => quote! { #enum_ident },
+    };
+
+    let (pats, tys): (Vec<_>, Vec<_>) = sig
+        .inputs
+        .iter()
+        .skip(1)
+        .filter_map(|a| match a {
+            syn::FnArg::Typed(p) => Some((&p.pat, &p.ty)),
+            _ => None,
+        })
+        .unzip();
+
+    let this = format_ident!("__disponent_this");
+    let table = format_ident!("__DISPONENT_TABLE");
+    let index = format_ident!("__disponent_index");
+    let len = variants.len();
+
+    let shim_idents: Vec<_> = (0..len)
+        .map(|i| format_ident!("__disponent_shim{}", i))
+        .collect();
+    let shims =
+        variants
+            .iter()
+            .zip(calls)
+            .zip(&shim_idents)
+            .map(|(((v, _, attrs), call), shim)| {
+                let cfg_attrs = attrs
+                    .iter()
+                    .filter(|a| crate::forward::is_attr_allowed(a, false));
+                quote! {
+                    #[allow(unused_variables, unreachable_patterns)]
+                    fn #shim<#(#lifetimes),*>(#this: #this_ty, #(#pats: #tys),*) #output {
+                        match #this {
+                            #(#cfg_attrs)* #enum_ident::#v(#inner) => #call,
+                            _ => ::core::unreachable!(),
+                        }
+                    }
+                }
+            });
+
+    let indices = variants.iter().enumerate().map(|(i, (v, _, attrs))| {
+        let cfg_attrs = attrs
+            .iter()
+            .filter(|a| crate::forward::is_attr_allowed(a, false));
+        quote! { #(#cfg_attrs)* #enum_ident::#v(_) => #i, }
+    });
+
+    let args = pats.iter();
+    let for_lifetimes = (!lifetimes.is_empty()).then(|| quote! { for<#(#lifetimes),*> });
+
+    quote! {
+        #(#shims)*
+        static #table: [#for_lifetimes fn(#this_ty, #(#tys),*) #output; #len] = [#(#shim_idents),*];
+        let #index: usize = match self { #(#indices)* };
+        #table[#index](self, #(#args),*)
+    }
+}
+
+/// Replaces elided lifetimes (`&T`, `'_`) with a named lifetime.
+struct ElidedLifetimes<'a>(&'a syn::Lifetime);
+
+impl VisitMut for ElidedLifetimes<'_> {
+    fn visit_type_reference_mut(&mut self, r: &mut syn::TypeReference) {
+        if r.lifetime.is_none() {
+            r.lifetime = Some(self.0.clone());
+        }
+        syn::visit_mut::visit_type_reference_mut(self, r);
+    }
+
+    fn visit_lifetime_mut(&mut self, l: &mut syn::Lifetime) {
+        if l.ident == "_" {
+            *l = self.0.clone();
+        }
+    }
+
+    // Function pointers have their own elision scope
+    fn visit_type_bare_fn_mut(&mut self, _: &mut syn::TypeBareFn) {}
+}
+
+/// Check whether any of `names` appears as an identifier in `tokens`.
+pub fn contains_ident(tokens: TokenStream, names: &[&str]) -> bool {
+    tokens.into_iter().any(|t| match t {
+        TokenTree::Ident(i) => names.iter().any(|name| i == name),
+        TokenTree::Group(g) => contains_ident(g.stream(), names),
+        _ => false,
+    })
+}
@@ -0,0 +1,101 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Result, ext::IdentExt, punctuated::Punctuated, spanned::Spanned};
+
+/// Wrap a generated method body in a `tracing` span named `Enum::method`.
+///
+/// The span records the active variant, and the arguments listed in `#[disponent::record(...)]`.
+/// Async methods are instrumented with `Instrument`, since holding a span guard across an
+/// `.await` would attach unrelated events to the span.
+pub fn instrument(
+    method: &syn::TraitItemFn,
+    sig: &syn::Signature,
+    args: &[syn::Ident],
+    enum_ident: &syn::Ident,
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+    body: TokenStream,
+) -> Result<TokenStream> {
+    let name = format!("{}::{}", enum_ident.unraw(), sig.ident.unraw());
+
+    let variant_names = variants.iter().map(|(v, _, attrs)| {
+        let cfg_attrs = attrs
+            .iter()
+            .filter(|a| crate::forward::is_attr_allowed(a, false));
+        let variant = v.unraw().to_string();
+        quote! { #(#cfg_attrs)* #enum_ident::#v(_) => #variant, }
+    });
+
+    let fields = recorded_args(method, args)?
+        .into_iter()
+        .map(|(name, arg)| quote! { #name = ?#arg });
+
+    let span = quote! {
+        ::tracing::trace_span!(
+            #name,
+            variant = match self { #(#variant_names)* },
+            #(#fields),*
+        )
+    };
+
+    Ok(match sig.asyncness {
+        Some(_) => quote! {
+            let __disponent_span = #span;
+            ::tracing::Instrument::instrument(async move { #body }, __disponent_span).await
+        },
+        None => quote! {
+            let __disponent_span = #span;
+            let __disponent_guard = __disponent_span.enter();
+            #body
+        },
+    })
+}
+
+/// The arguments listed in `#[disponent::record(...)]`, which have to be plain identifiers,
+/// with the names they are rebound to in the generated method.
+fn recorded_args(
+    method: &syn::TraitItemFn,
+    args: &[syn::Ident],
+) -> Result<Vec<(syn::Ident, syn::Ident)>> {
+    let Some(attr) = crate::helper_attr(&method.attrs, "record") else {
+        return Ok(Vec::new());
+    };
+
+    let names: Vec<_> = method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|a| match a {
+            syn::FnArg::Typed(p) => Some(match &*p.pat {
+                syn::Pat::Ident(pat) => Some(&pat.ident),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .zip(args)
+        .filter_map(|(name, arg)| Some((name?, arg)))
+        .collect();
+
+    let recorded =
+        attr.parse_args_with(Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated)?;
+    recorded
+        .into_iter()
+        .map(|name| match names.iter().find(|(n, _)| **n == name) {
+            Some((_, arg)) => Ok((name, (*arg).clone())),
+            None => Err(syn::Error::new(
+                name.span(),
+                format!("Unknown argument `{name}`"),
+            )),
+        })
+        .collect()
+}
+
+/// Reject `#[disponent::record(...)]` when spans aren't generated.
+pub fn check_unused(method: &syn::TraitItemFn) -> Result<()> {
+    match crate::helper_attr(&method.attrs, "record") {
+        Some(attr) => Err(syn::Error::new(
+            attr.span(),
+            "`#[disponent::record(...)]` requires `tracing` in `#[disponent::configure(...)]`",
+        )),
+        None => Ok(()),
+    }
+}
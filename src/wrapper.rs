@@ -0,0 +1,356 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Result, spanned::Spanned};
+
+/// Traits that a generated wrapper enum can implement by delegating to its variants.
+const SUPPORTED_TRAITS: &[&str] = &[
+    "Iterator",
+    "DoubleEndedIterator",
+    "ExactSizeIterator",
+    "FusedIterator",
+    "Debug",
+    "Display",
+    "Error",
+    "Deref",
+    "DerefMut",
+];
+
+/// Auto traits that need no impl, the wrapper enum implements them if every variant does.
+const AUTO_TRAITS: &[&str] = &["Send", "Sync", "Unpin", "UnwindSafe", "RefUnwindSafe"];
+
+/// Generate an enum for an `impl Trait` return type that holds the return value of each variant.
+///
+/// The enum is generic over one type parameter per variant, so it can hold opaque types.
+pub fn return_enum(
+    wrapper_ident: &syn::Ident,
+    doc: &str,
+    vis: &syn::Visibility,
+    impl_trait: &syn::TypeImplTrait,
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+) -> Result<TokenStream> {
+    check_variants(variants, impl_trait.span(), "`impl Trait` return types")?;
+
+    let params: Vec<_> = (0..variants.len())
+        .map(|i| format_ident!("T{}", i))
+        .collect();
+    let variant_idents = variants.iter().map(|(v, _, _)| v);
+
+    let field_tys: Vec<_> = params.iter().map(|p| quote! { #p }).collect();
+    let impls = trait_impls(
+        &impl_trait.bounds,
+        &quote! { <#(#params),*> },
+        &quote! { #wrapper_ident<#(#params),*> },
+        wrapper_ident,
+        &field_tys,
+        variants,
+        true,
+        |name| format!("Cannot forward `impl {name}` return types"),
+    )?;
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis enum #wrapper_ident<#(#params),*> {
+            #(#variant_idents(#params)),*
+        }
+
+        #impls
+    })
+}
+
+/// Generate a lifetime generic enum for a generic associated type like `type Guard<'a>`,
+/// that holds each variant's `Guard<'a>`.
+///
+/// It implements the traits the associated type is bounded by, like the return enums above.
+pub fn companion_enum(
+    companion_ident: &syn::Ident,
+    doc: &str,
+    vis: &syn::Visibility,
+    trait_path: &syn::Path,
+    gat: &syn::TraitItemType,
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+) -> Result<TokenStream> {
+    check_variants(variants, gat.span(), "generic associated types")?;
+
+    if let Some(param) = gat
+        .generics
+        .params
+        .iter()
+        .find(|p| !matches!(p, syn::GenericParam::Lifetime(_)))
+    {
+        return Err(syn::Error::new(
+            param.span(),
+            "Only lifetime parameters are supported on generic associated types",
+        ));
+    }
+
+    let gat_ident = &gat.ident;
+    let lifetimes: Vec<_> = gat.generics.lifetimes().map(|l| &l.lifetime).collect();
+    let variant_idents = variants.iter().map(|(v, _, _)| v);
+    let field_tys: Vec<_> = variants
+        .iter()
+        .map(|(_, ty, _)| quote! { <#ty as #trait_path>::#gat_ident<#(#lifetimes),*> })
+        .collect();
+
+    let impls = trait_impls(
+        &gat.bounds,
+        &quote! { <#(#lifetimes),*> },
+        &quote! { #companion_ident<#(#lifetimes),*> },
+        companion_ident,
+        &field_tys,
+        variants,
+        false,
+        |name| format!("Cannot forward `{name}` bounds of generic associated types"),
+    )?;
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis enum #companion_ident<#(#lifetimes),*> {
+            #(#variant_idents(#field_tys)),*
+        }
+
+        #impls
+    })
+}
+
+/// Wrapper enums name the type of every variant, so they can't have `#[cfg]`'d variants.
+fn check_variants(
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+    span: proc_macro2::Span,
+    what: &str,
+) -> Result<()> {
+    if let Some((ident, _, _)) = variants.iter().find(|(_, _, attrs)| {
+        attrs
+            .iter()
+            .any(|a| a.path().is_ident("cfg") || a.path().is_ident("cfg_attr"))
+    }) {
+        return Err(syn::Error::new(
+            ident.span(),
+            format!("{what} cannot be forwarded when variants have `#[cfg]` attributes"),
+        ));
+    }
+
+    if variants.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            format!("{what} can only be forwarded for enums with at least one variant"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Implement the supported traits among `bounds` (and their supertraits) for a wrapper enum
+/// by delegating to its variants.
+///
+/// `bounded` adds where clauses requiring the traits from the variants' types. Types that are
+/// already bounded by the trait must not repeat them, since a where clause on a projection
+/// shadows the impl that would normalize it.
+#[allow(clippy::too_many_arguments)]
+fn trait_impls(
+    bounds: &syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]>,
+    impl_generics: &TokenStream,
+    self_ty: &TokenStream,
+    wrapper_ident: &syn::Ident,
+    field_tys: &[TokenStream],
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+    bounded: bool,
+    unsupported: impl Fn(&str) -> String,
+) -> Result<TokenStream> {
+    let mut names = Vec::new();
+    for bound in bounds {
+        let syn::TypeParamBound::Trait(t) = bound else {
+            continue;
+        };
+        let Some(name) = t.path.segments.last().map(|s| s.ident.to_string()) else {
+            continue;
+        };
+
+        if AUTO_TRAITS.contains(&name.as_str()) {
+            continue;
+        }
+
+        if !SUPPORTED_TRAITS.contains(&name.as_str()) {
+            return Err(syn::Error::new(
+                t.span(),
+                format!(
+                    "{}. Supported traits are: {}",
+                    unsupported(&name),
+                    SUPPORTED_TRAITS.join(", ")
+                ),
+            ));
+        }
+
+        // Supertraits need an impl as well, even if they are not listed
+        let implied: &[&str] = match name.as_str() {
+            "DoubleEndedIterator" | "ExactSizeIterator" | "FusedIterator" => &["Iterator"],
+            "Error" => &["Debug", "Display"],
+            "DerefMut" => &["Deref"],
+            _ => &[],
+        };
+        names.extend(implied.iter().map(|n| n.to_string()));
+        names.push(name);
+    }
+
+    Ok(SUPPORTED_TRAITS
+        .iter()
+        .filter(|name| names.iter().any(|n| n == *name))
+        .map(|name| {
+            impl_trait_for(
+                name,
+                impl_generics,
+                self_ty,
+                wrapper_ident,
+                field_tys,
+                variants,
+                bounded,
+            )
+        })
+        .collect())
+}
+
+fn impl_trait_for(
+    name: &str,
+    impl_generics: &TokenStream,
+    self_ty: &TokenStream,
+    wrapper_ident: &syn::Ident,
+    params: &[TokenStream],
+    variants: &[(&syn::Ident, &syn::Type, &Vec<syn::Attribute>)],
+    bounded: bool,
+) -> TokenStream {
+    let first = &params[0];
+    let rest = &params[1..];
+    let arm = |body: TokenStream| {
+        let arms = variants
+            .iter()
+            .map(|(v, _, _)| quote! { #wrapper_ident::#v(inner) => #body, });
+        quote! { match self { #(#arms)* } }
+    };
+
+    let (trait_path, bounds, body) = match name {
+        "Iterator" => (
+            quote! { ::core::iter::Iterator },
+            quote! { #first: ::core::iter::Iterator, #(#rest: ::core::iter::Iterator<Item = <#first as ::core::iter::Iterator>::Item>),* },
+            {
+                let next = arm(quote! { ::core::iter::Iterator::next(inner) });
+                let size_hint = arm(quote! { ::core::iter::Iterator::size_hint(inner) });
+                quote! {
+                    type Item = <#first as ::core::iter::Iterator>::Item;
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> { #next }
+                    fn size_hint(&self) -> (usize, ::core::option::Option<usize>) { #size_hint }
+                }
+            },
+        ),
+        "DoubleEndedIterator" => (
+            quote! { ::core::iter::DoubleEndedIterator },
+            quote! { #first: ::core::iter::DoubleEndedIterator, #(#rest: ::core::iter::DoubleEndedIterator<Item = <#first as ::core::iter::Iterator>::Item>),* },
+            {
+                let next_back = arm(quote! { ::core::iter::DoubleEndedIterator::next_back(inner) });
+                quote! {
+                    fn next_back(&mut self) -> ::core::option::Option<Self::Item> { #next_back }
+                }
+            },
+        ),
+        "ExactSizeIterator" => (
+            quote! { ::core::iter::ExactSizeIterator },
+            quote! { #first: ::core::iter::ExactSizeIterator, #(#rest: ::core::iter::ExactSizeIterator<Item = <#first as ::core::iter::Iterator>::Item>),* },
+            {
+                let len = arm(quote! { ::core::iter::ExactSizeIterator::len(inner) });
+                quote! { fn len(&self) -> usize { #len } }
+            },
+        ),
+        "FusedIterator" => (
+            quote! { ::core::iter::FusedIterator },
+            quote! { #first: ::core::iter::FusedIterator, #(#rest: ::core::iter::FusedIterator<Item = <#first as ::core::iter::Iterator>::Item>),* },
+            TokenStream::new(),
+        ),
+        "Debug" | "Display" => {
+            let trait_ident = format_ident!("{}", name);
+            let fmt = arm(quote! { ::core::fmt::#trait_ident::fmt(inner, f) });
+            (
+                quote! { ::core::fmt::#trait_ident },
+                quote! { #(#params: ::core::fmt::#trait_ident),* },
+                quote! {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { #fmt }
+                },
+            )
+        }
+        "Error" => {
+            let source = arm(quote! { ::core::error::Error::source(inner) });
+            (
+                quote! { ::core::error::Error },
+                quote! { #(#params: ::core::error::Error),* },
+                quote! {
+                    fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> { #source }
+                },
+            )
+        }
+        "Deref" => (
+            quote! { ::core::ops::Deref },
+            quote! { #first: ::core::ops::Deref, #(#rest: ::core::ops::Deref<Target = <#first as ::core::ops::Deref>::Target>),* },
+            {
+                let deref = arm(quote! { ::core::ops::Deref::deref(inner) });
+                quote! {
+                    type Target = <#first as ::core::ops::Deref>::Target;
+                    fn deref(&self) -> &Self::Target { #deref }
+                }
+            },
+        ),
+        "DerefMut" => (
+            quote! { ::core::ops::DerefMut },
+            quote! { #first: ::core::ops::DerefMut, #(#rest: ::core::ops::DerefMut<Target = <#first as ::core::ops::Deref>::Target>),* },
+            {
+                let deref_mut = arm(quote! { ::core::ops::DerefMut::deref_mut(inner) });
+                quote! {
+                    fn deref_mut(&mut self) -> &mut Self::Target { #deref_mut }
+                }
+            },
+        ),
+        _ => unreachable!("unsupported traits are rejected before generating impls"),
+    };
+
+    let where_clause = bounded.then(|| quote! { where #bounds });
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #self_ty #where_clause {
+            #body
+        }
+    }
+}
+
+/// The associated type segment `X<..>` of a `Self::X<..>` return type.
+pub fn gat_output(output: &syn::ReturnType) -> Option<&syn::PathSegment> {
+    let syn::ReturnType::Type(_, ty) = output else {
+        return None;
+    };
+    let syn::Type::Path(p) = &**ty else {
+        return None;
+    };
+    match p.path.segments.iter().collect::<Vec<_>>()[..] {
+        [this, assoc]
+            if p.qself.is_none()
+                && this.ident == "Self"
+                && this.arguments.is_empty()
+                && matches!(assoc.arguments, syn::PathArguments::AngleBracketed(_)) =>
+        {
+            Some(assoc)
+        }
+        _ => None,
+    }
+}
+
+/// Convert a method name like `bytes_iter` to `BytesIter`.
+pub fn to_pascal_case(ident: &syn::Ident) -> String {
+    ident
+        .to_string()
+        .trim_start_matches("r#")
+        .split('_')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
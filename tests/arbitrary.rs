@@ -0,0 +1,86 @@
+#![cfg(all(feature = "arbitrary", feature = "proptest"))]
+
+use proptest::{
+    arbitrary::{Arbitrary, any},
+    strategy::{BoxedStrategy, Strategy},
+};
+
+disponent::declare!(
+    #[disponent::configure(arbitrary, proptest)]
+    #[derive(Debug, Clone)]
+    pub enum Shape {
+        Circle(Circle),
+        #[disponent::weight(3)]
+        Square(Square),
+        #[disponent::weight(0)]
+        Never(Square),
+        #[cfg(any())]
+        Disabled(Circle),
+    }
+
+    pub trait Area {
+        fn area(&self) -> u32;
+    }
+);
+
+#[derive(Debug, Clone, arbitrary::Arbitrary)]
+pub struct Circle(pub u8);
+
+#[derive(Debug, Clone, arbitrary::Arbitrary)]
+pub struct Square(pub u8);
+
+impl Arbitrary for Circle {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        any::<u8>().prop_map(Circle).boxed()
+    }
+}
+
+impl Arbitrary for Square {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        any::<u8>().prop_map(Square).boxed()
+    }
+}
+
+impl Area for Circle {
+    fn area(&self) -> u32 {
+        3 * self.0 as u32 * self.0 as u32
+    }
+}
+
+impl Area for Square {
+    fn area(&self) -> u32 {
+        self.0 as u32 * self.0 as u32
+    }
+}
+
+#[test]
+fn test_arbitrary_weights() {
+    let data: Vec<u8> = (0..4096u32).map(|i| (i * 7919 % 251) as u8).collect();
+    let mut u = arbitrary::Unstructured::new(&data);
+
+    let (mut circles, mut squares) = (0, 0);
+    for _ in 0..512 {
+        match <Shape as arbitrary::Arbitrary>::arbitrary(&mut u).unwrap() {
+            Shape::Circle(_) => circles += 1,
+            Shape::Square(_) => squares += 1,
+            Shape::Never(_) => panic!("variants with weight 0 are never picked"),
+        }
+    }
+
+    assert!(circles > 0);
+    assert!(squares > circles);
+}
+
+proptest::proptest! {
+    #[test]
+    fn test_proptest(shape in any::<Shape>()) {
+        proptest::prop_assert!(!matches!(shape, Shape::Never(_)));
+        proptest::prop_assert!(shape.area() <= 3 * 255 * 255);
+    }
+}
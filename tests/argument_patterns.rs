@@ -0,0 +1,122 @@
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Patterns {
+        fn ignored(&self, _: u32) -> u32;
+        fn tuple(&self, (a, b): (u8, u8)) -> u8 {
+            a + b
+        }
+        fn point(&self, Point { x, y }: Point) -> i32 {
+            x * y
+        }
+        fn push(&self, mut buf: Vec<u8>) -> Vec<u8> {
+            buf.push(0);
+            buf
+        }
+        fn inner(&self, inner: i32, __disponent_inner: i32) -> i32;
+        fn synthesized(&self, _: u32, arg0: u32) -> u32;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub), dispatch = "table")]
+    pub enum InherentFooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait InherentPatterns {
+        fn tuple(&self, (a, b): (u8, u8)) -> u8 {
+            a + b
+        }
+        fn ignored(&self, _: u32, _: u32) -> u32;
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+
+impl Patterns for Foo {
+    fn ignored(&self, _: u32) -> u32 {
+        1
+    }
+
+    fn inner(&self, inner: i32, __disponent_inner: i32) -> i32 {
+        inner - __disponent_inner
+    }
+
+    fn synthesized(&self, _: u32, arg0: u32) -> u32 {
+        arg0
+    }
+}
+
+impl Patterns for Bar {
+    fn ignored(&self, value: u32) -> u32 {
+        value
+    }
+
+    fn tuple(&self, (a, b): (u8, u8)) -> u8 {
+        a * b
+    }
+
+    fn inner(&self, inner: i32, __disponent_inner: i32) -> i32 {
+        inner + __disponent_inner
+    }
+
+    fn synthesized(&self, first: u32, arg0: u32) -> u32 {
+        first + arg0
+    }
+}
+
+impl InherentPatterns for Foo {
+    fn ignored(&self, a: u32, _: u32) -> u32 {
+        a
+    }
+}
+
+impl InherentPatterns for Bar {
+    fn tuple(&self, (a, b): (u8, u8)) -> u8 {
+        a * b
+    }
+
+    fn ignored(&self, _: u32, b: u32) -> u32 {
+        b
+    }
+}
+
+#[test]
+fn test_patterns() {
+    let foo = FooOrBar::Foo(Foo);
+    let bar = FooOrBar::Bar(Bar);
+
+    assert_eq!(foo.ignored(5), 1);
+    assert_eq!(bar.ignored(5), 5);
+    assert_eq!(foo.tuple((2, 3)), 5);
+    assert_eq!(bar.tuple((2, 3)), 6);
+    assert_eq!(foo.point(Point { x: 2, y: 4 }), 8);
+    assert_eq!(bar.push(vec![1]), vec![1, 0]);
+}
+
+#[test]
+fn test_no_reserved_names() {
+    assert_eq!(FooOrBar::Foo(Foo).inner(5, 3), 2);
+    assert_eq!(FooOrBar::Bar(Bar).inner(5, 3), 8);
+    assert_eq!(FooOrBar::Foo(Foo).synthesized(5, 3), 3);
+    assert_eq!(FooOrBar::Bar(Bar).synthesized(5, 3), 8);
+}
+
+#[test]
+fn test_patterns_inherent() {
+    assert_eq!(InherentFooOrBar::Foo(Foo).tuple((2, 3)), 5);
+    assert_eq!(InherentFooOrBar::Bar(Bar).tuple((2, 3)), 6);
+    assert_eq!(InherentFooOrBar::Foo(Foo).ignored(1, 2), 1);
+    assert_eq!(InherentFooOrBar::Bar(Bar).ignored(1, 2), 2);
+}
@@ -0,0 +1,15 @@
+disponent::declare!(
+    #[disponent::configure(inline(always))]
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait SayHello {
+        #[cold]
+        fn say_hello(&self);
+    }
+);
+
+pub struct Foo;
+
+fn main() {}
@@ -0,0 +1,19 @@
+disponent::declare!(
+    pub enum Storage {
+        Memory(Memory),
+        #[disponent::dynamic]
+        Custom(Box<dyn Backend>),
+    }
+
+    pub trait Backend {
+        fn name(&self) -> String;
+
+        fn bytes(&self) -> impl Iterator<Item = u8>
+        where
+            Self: Sized;
+    }
+);
+
+pub struct Memory;
+
+fn main() {}
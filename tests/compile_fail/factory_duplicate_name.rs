@@ -0,0 +1,16 @@
+disponent::declare!(
+    #[disponent::configure(factory)]
+    pub enum FooOrBar {
+        #[disponent::name("foo")]
+        Foo(Foo),
+        #[disponent::name("foo")]
+        Bar(Foo),
+    }
+
+    pub trait SayHello {}
+);
+
+#[derive(Default)]
+pub struct Foo;
+
+fn main() {}
@@ -0,0 +1,19 @@
+disponent::declare!(
+    pub enum Pool {
+        Heap(Heap),
+        Stack(Stack),
+    }
+
+    pub trait Lease {
+        type Guard<'a>
+        where
+            Self: 'a;
+
+        fn give_back(&mut self, guard: Self::Guard<'_>);
+    }
+);
+
+pub struct Heap;
+pub struct Stack;
+
+fn main() {}
@@ -0,0 +1,19 @@
+disponent::declare!(
+    pub enum Pool {
+        Heap(Heap),
+        Stack(Stack),
+    }
+
+    pub trait Lease {
+        type Guard<'a>
+        where
+            Self: 'a;
+
+        fn try_lease(&mut self) -> Option<Self::Guard<'_>>;
+    }
+);
+
+pub struct Heap;
+pub struct Stack;
+
+fn main() {}
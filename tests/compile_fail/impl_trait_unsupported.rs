@@ -0,0 +1,13 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait Callback {
+        fn callback(&self) -> impl Fn(u32) -> u32;
+    }
+);
+
+pub struct Foo;
+
+fn main() {}
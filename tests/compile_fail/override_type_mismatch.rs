@@ -0,0 +1,24 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        #[disponent::override_with(name = wrong_name)]
+        Foo(Foo),
+    }
+
+    pub trait Named {
+        fn name(&self) -> &'static str;
+    }
+);
+
+pub struct Foo;
+
+impl Named for Foo {
+    fn name(&self) -> &'static str {
+        "foo"
+    }
+}
+
+fn wrong_name(_: &Foo) -> u32 {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        #[disponent::override_with(say_goodbye = say_nothing)]
+        Foo(Foo),
+    }
+
+    pub trait SayHello {
+        fn say_hello(&self);
+    }
+);
+
+pub struct Foo;
+
+fn say_nothing(_: &Foo) {}
+
+fn main() {}
@@ -0,0 +1,16 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait SayHello {
+        #[disponent::override_with(Bar => say_nothing)]
+        fn say_hello(&self);
+    }
+);
+
+pub struct Foo;
+
+fn say_nothing(_: &Foo) {}
+
+fn main() {}
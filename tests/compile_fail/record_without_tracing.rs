@@ -0,0 +1,14 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait SayHello {
+        #[disponent::record(name)]
+        fn say_hello(&self, name: &str);
+    }
+);
+
+pub struct Foo;
+
+fn main() {}
@@ -0,0 +1,15 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Merge {
+        fn merge(&mut self, others: &[Self]);
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+
+fn main() {}
@@ -0,0 +1,40 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Source {
+        fn read(&self) -> Vec<u8>;
+
+        fn dup(&self) -> Vec<u8>
+        where
+            Self: Clone;
+    }
+);
+
+#[derive(Clone)]
+pub struct Foo;
+pub struct Bar;
+
+impl Source for Foo {
+    fn read(&self) -> Vec<u8> {
+        vec![1]
+    }
+
+    fn dup(&self) -> Vec<u8> {
+        self.clone().read()
+    }
+}
+
+impl Source for Bar {
+    fn read(&self) -> Vec<u8> {
+        vec![2]
+    }
+
+    fn dup(&self) -> Vec<u8> {
+        self.read()
+    }
+}
+
+fn main() {}
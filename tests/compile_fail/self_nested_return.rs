@@ -0,0 +1,16 @@
+disponent::declare!(
+    #[disponent::configure(inherent)]
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Split {
+        fn split(&self) -> (Self, Self);
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+
+fn main() {}
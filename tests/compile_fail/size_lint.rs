@@ -0,0 +1,28 @@
+disponent::declare!(
+    #[disponent::configure(size_lint(max = 64, ratio = 16))]
+    pub enum Storage {
+        Small(Small),
+        Large(Large),
+    }
+
+    pub trait Capacity {
+        fn capacity(&self) -> usize;
+    }
+);
+
+pub struct Small(u32);
+pub struct Large([u32; 32]);
+
+impl Capacity for Small {
+    fn capacity(&self) -> usize {
+        1
+    }
+}
+
+impl Capacity for Large {
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+fn main() {}
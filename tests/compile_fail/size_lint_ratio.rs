@@ -0,0 +1,28 @@
+disponent::declare!(
+    #[disponent::configure(size_lint(ratio = 4))]
+    pub enum Storage {
+        Small(Small),
+        r#Large(Large),
+    }
+
+    pub trait Capacity {
+        fn capacity(&self) -> usize;
+    }
+);
+
+pub struct Small(u32);
+pub struct Large([u32; 8]);
+
+impl Capacity for Small {
+    fn capacity(&self) -> usize {
+        1
+    }
+}
+
+impl Capacity for Large {
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait SayHello {
+        #[disponent::skip]
+        fn say_hello(&self);
+    }
+);
+
+pub struct Foo;
+
+fn main() {}
@@ -0,0 +1,18 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait SayHello: Clone {
+        fn say_hello(&self);
+    }
+);
+
+#[derive(Clone)]
+pub struct Foo;
+
+impl SayHello for Foo {
+    fn say_hello(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait SayHello {
+        #[track_caller]
+        fn say_hello(&self) -> impl Future<Output = ()>;
+    }
+);
+
+pub struct Foo;
+
+fn main() {}
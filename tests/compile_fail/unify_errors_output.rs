@@ -0,0 +1,31 @@
+disponent::declare!(
+    #[disponent::configure(unify_errors)]
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait Produce {
+        type Output;
+
+        fn produce(&self) -> Self::Output;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(unify_errors)]
+    pub enum BarOrBaz {
+        Bar(Bar),
+    }
+
+    pub trait Fallible {
+        type Error;
+
+        fn run(&self) -> Result<(), Self::Error>;
+        fn last_error(&self) -> Option<Self::Error>;
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+
+fn main() {}
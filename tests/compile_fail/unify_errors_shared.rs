@@ -0,0 +1,36 @@
+use std::io;
+
+disponent::declare!(
+    #[disponent::configure(unify_errors)]
+    pub enum Storage {
+        File(File),
+        Socket(Socket),
+    }
+
+    pub trait Write {
+        type Error;
+
+        fn write(&mut self, data: &[u8]) -> Result<usize, Self::Error>;
+    }
+);
+
+pub struct File;
+pub struct Socket;
+
+impl Write for File {
+    type Error = io::Error;
+
+    fn write(&mut self, data: &[u8]) -> Result<usize, Self::Error> {
+        Ok(data.len())
+    }
+}
+
+impl Write for Socket {
+    type Error = io::Error;
+
+    fn write(&mut self, data: &[u8]) -> Result<usize, Self::Error> {
+        Ok(data.len())
+    }
+}
+
+fn main() {}
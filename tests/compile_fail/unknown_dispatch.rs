@@ -0,0 +1,14 @@
+disponent::declare!(
+    #[disponent::configure(dispatch = "vtable")]
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait SayHello {
+        fn say_hello(&self);
+    }
+);
+
+pub struct Foo;
+
+fn main() {}
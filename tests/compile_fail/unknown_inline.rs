@@ -0,0 +1,14 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait SayHello {
+        #[disponent::inline(sometimes)]
+        fn say_hello(&self);
+    }
+);
+
+pub struct Foo;
+
+fn main() {}
@@ -0,0 +1,139 @@
+disponent::declare!(
+    #[disponent::configure(from)]
+    pub enum Storage {
+        Memory(Memory),
+        #[disponent::dynamic(fallback = panic)]
+        #[disponent::override_with(snapshot = |_| Storage::Memory(Memory::default()))]
+        Custom(Box<dyn Backend + Send>),
+    }
+
+    pub trait Backend {
+        fn name(&self) -> String;
+        fn write(&mut self, data: &[u8]) -> usize;
+
+        fn snapshot(&self) -> Self
+        where
+            Self: Sized;
+
+        fn bytes(&self) -> impl Iterator<Item = u8>
+        where
+            Self: Sized;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub), dispatch = "table")]
+    pub enum InherentStorage {
+        Memory(Memory),
+        #[disponent::dynamic]
+        Custom(Box<dyn InherentBackend>),
+    }
+
+    pub trait InherentBackend {
+        fn name(&self) -> String;
+        fn write(&mut self, data: &[u8]) -> usize;
+    }
+);
+
+#[derive(Default)]
+pub struct Memory(Vec<u8>);
+
+pub struct Counter(usize);
+
+impl Backend for Memory {
+    fn name(&self) -> String {
+        "memory".into()
+    }
+
+    fn write(&mut self, data: &[u8]) -> usize {
+        self.0.extend_from_slice(data);
+        data.len()
+    }
+
+    fn snapshot(&self) -> Self {
+        Memory(self.0.clone())
+    }
+
+    fn bytes(&self) -> impl Iterator<Item = u8> {
+        self.0.clone().into_iter()
+    }
+}
+
+impl Backend for Counter {
+    fn name(&self) -> String {
+        format!("counter {}", self.0)
+    }
+
+    fn write(&mut self, data: &[u8]) -> usize {
+        self.0 += data.len();
+        0
+    }
+
+    fn snapshot(&self) -> Self {
+        Counter(self.0)
+    }
+
+    fn bytes(&self) -> impl Iterator<Item = u8> {
+        std::iter::empty()
+    }
+}
+
+impl InherentBackend for Memory {
+    fn name(&self) -> String {
+        "memory".into()
+    }
+
+    fn write(&mut self, data: &[u8]) -> usize {
+        self.0.extend_from_slice(data);
+        data.len()
+    }
+}
+
+impl InherentBackend for Counter {
+    fn name(&self) -> String {
+        format!("counter {}", self.0)
+    }
+
+    fn write(&mut self, data: &[u8]) -> usize {
+        self.0 += data.len();
+        0
+    }
+}
+
+#[test]
+fn test_dynamic_dispatch() {
+    let boxed: Box<dyn Backend + Send> = Box::new(Counter(0));
+    let mut storage = Storage::from(boxed);
+    assert_eq!(storage.write(b"abc"), 0);
+    assert_eq!(storage.name(), "counter 3");
+
+    let mut memory = Storage::from(Memory::default());
+    assert_eq!(memory.write(b"abc"), 3);
+    assert_eq!(memory.name(), "memory");
+}
+
+#[test]
+fn test_dynamic_fallback_override() {
+    let storage = Storage::Custom(Box::new(Counter(1)));
+    assert!(matches!(storage.snapshot(), Storage::Memory(_)));
+
+    let mut memory = Storage::Memory(Memory::default());
+    memory.write(b"ab");
+    assert!(matches!(memory.snapshot(), Storage::Memory(Memory(m)) if m == b"ab"));
+    assert_eq!(memory.bytes().collect::<Vec<_>>(), b"ab");
+}
+
+#[test]
+#[should_panic(expected = "`bytes` can't be called through `dyn Backend` on `Storage::Custom`")]
+fn test_dynamic_fallback_panics() {
+    let storage = Storage::Custom(Box::new(Counter(1)));
+    let _ = storage.bytes();
+}
+
+#[test]
+fn test_dynamic_inherent() {
+    let mut storage = InherentStorage::from(Box::new(Counter(0)) as Box<dyn InherentBackend>);
+    storage.write(b"abcd");
+    assert_eq!(storage.name(), "counter 4");
+    assert_eq!(InherentStorage::Memory(Memory::default()).name(), "memory");
+}
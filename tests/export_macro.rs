@@ -0,0 +1,125 @@
+disponent::declare!(
+    #[disponent::configure(export_macro)]
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Named {
+        fn name(&self) -> &'static str;
+    }
+);
+
+pub mod shapes {
+    disponent::declare!(
+        #[disponent::configure(export_macro(module = crate::shapes))]
+        pub enum Shape {
+            Square(f64),
+            Circle(f64),
+        }
+
+        pub trait Area {
+            fn area(&self) -> f64;
+        }
+    );
+
+    impl Area for f64 {
+        fn area(&self) -> f64 {
+            self * self
+        }
+    }
+}
+
+pub struct Foo(usize);
+pub struct Bar(usize);
+
+impl Named for Foo {
+    fn name(&self) -> &'static str {
+        "foo"
+    }
+}
+
+impl Named for Bar {
+    fn name(&self) -> &'static str {
+        "bar"
+    }
+}
+
+// A trait that `declare!` doesn't know about, as it would be in another crate
+pub trait Shout {
+    fn shout(&self, times: usize) -> String;
+    fn label<'a>(&'a self, prefix: &'a str) -> &'a str;
+    fn bump(&mut self);
+    fn into_count(self) -> usize;
+}
+
+impl Shout for Foo {
+    fn shout(&self, times: usize) -> String {
+        "FOO".repeat(times)
+    }
+
+    fn label<'a>(&'a self, prefix: &'a str) -> &'a str {
+        prefix
+    }
+
+    fn bump(&mut self) {
+        self.0 += 1;
+    }
+
+    fn into_count(self) -> usize {
+        self.0
+    }
+}
+
+impl Shout for Bar {
+    fn shout(&self, times: usize) -> String {
+        "BAR".repeat(times)
+    }
+
+    fn label<'a>(&'a self, _prefix: &'a str) -> &'a str {
+        "bar"
+    }
+
+    fn bump(&mut self) {
+        self.0 += 2;
+    }
+
+    fn into_count(self) -> usize {
+        self.0
+    }
+}
+
+foo_or_bar_dispatch!(impl Shout {
+    fn shout(&self, times: usize) -> String;
+    #[inline]
+    fn label<'a>(&'a self, prefix: &'a str) -> &'a str;
+    fn bump(&mut self);
+    fn into_count(self) -> usize;
+});
+
+#[test]
+fn match_on_variants() {
+    let value = FooOrBar::Bar(Bar(0));
+    assert_eq!(foo_or_bar_dispatch!(&value, inner => inner.name()), "bar");
+    assert_eq!(foo_or_bar_dispatch!(value, inner => inner.0), 0);
+}
+
+#[test]
+fn forward_trait() {
+    let mut foo = FooOrBar::Foo(Foo(0));
+    assert_eq!(foo.shout(2), "FOOFOO");
+    assert_eq!(foo.label("prefix"), "prefix");
+    foo.bump();
+    assert_eq!(foo.into_count(), 1);
+
+    let mut bar = FooOrBar::Bar(Bar(0));
+    assert_eq!(bar.label("prefix"), "bar");
+    bar.bump();
+    assert_eq!(bar.into_count(), 2);
+}
+
+#[test]
+fn enum_in_module() {
+    let shape = shapes::Shape::Circle(2.0);
+    assert_eq!(shape_dispatch!(&shape, inner => *inner), 2.0);
+}
@@ -0,0 +1,122 @@
+use std::{error::Error, str::FromStr};
+
+disponent::declare!(
+    #[disponent::configure(factory)]
+    #[derive(Debug, PartialEq)]
+    pub enum Cache {
+        #[disponent::name("memory")]
+        Memory(Memory),
+        #[disponent::name("redis")]
+        #[disponent::factory(FromStr)]
+        Redis(Redis),
+        #[disponent::factory(Disk::open)]
+        Disk(Disk),
+        #[cfg(any())]
+        Disabled(Memory),
+        #[cfg(test)]
+        #[disponent::name("tmp")]
+        Temporary(Memory),
+        #[cfg(not(test))]
+        #[disponent::name("tmp")]
+        LegacyTemporary(Memory),
+    }
+
+    pub trait Store {
+        fn describe(&self) -> String;
+    }
+);
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Memory;
+
+#[derive(Debug, PartialEq)]
+pub struct Redis(String);
+
+#[derive(Debug, PartialEq)]
+pub struct Disk(u32);
+
+impl FromStr for Redis {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("redis://") {
+            Some(host) => Ok(Redis(host.to_string())),
+            None => Err("expected a redis:// url"),
+        }
+    }
+}
+
+impl Disk {
+    fn open(args: &str) -> Result<Self, std::num::ParseIntError> {
+        Ok(Disk(args.parse()?))
+    }
+}
+
+impl Store for Memory {
+    fn describe(&self) -> String {
+        "memory".into()
+    }
+}
+
+impl Store for Redis {
+    fn describe(&self) -> String {
+        format!("redis at {}", self.0)
+    }
+}
+
+impl Store for Disk {
+    fn describe(&self) -> String {
+        format!("disk with {} blocks", self.0)
+    }
+}
+
+#[test]
+fn test_from_name() {
+    assert_eq!(
+        Cache::from_name("memory", "").unwrap(),
+        Cache::Memory(Memory)
+    );
+    assert_eq!(
+        Cache::from_name("redis", "redis://localhost").unwrap(),
+        Cache::Redis(Redis("localhost".into()))
+    );
+    assert_eq!(
+        Cache::from_name("Disk", "16").unwrap().describe(),
+        "disk with 16 blocks"
+    );
+    assert_eq!(
+        Cache::from_name("tmp", "").unwrap(),
+        Cache::Temporary(Memory)
+    );
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(Cache::names(), ["memory", "redis", "Disk", "tmp"]);
+}
+
+#[test]
+fn test_unknown_name() {
+    let error = Cache::from_name("Disabled", "").unwrap_err();
+    assert!(matches!(error, CacheFactoryError::UnknownVariant { .. }));
+    assert_eq!(
+        error.to_string(),
+        "unknown variant `Disabled`, expected one of `memory`, `redis`, `Disk`, `tmp`"
+    );
+}
+
+#[test]
+fn test_invalid_args() {
+    let error = Cache::from_name("redis", "localhost").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid arguments for `redis`: expected a redis:// url"
+    );
+
+    let error = Cache::from_name("Disk", "lots").unwrap_err();
+    assert!(matches!(
+        error,
+        CacheFactoryError::Invalid { name: "Disk", .. }
+    ));
+    assert!(error.source().is_some());
+}
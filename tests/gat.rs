@@ -0,0 +1,107 @@
+use std::ops::{Deref, DerefMut};
+
+disponent::declare!(
+    pub enum Pool {
+        Heap(Heap),
+        Stack(Stack),
+    }
+
+    pub trait Lease {
+        type Guard<'a>: DerefMut<Target = [u8]>
+        where
+            Self: 'a;
+
+        fn lease(&mut self) -> Self::Guard<'_>;
+        fn leased(&self) -> usize;
+    }
+);
+
+#[derive(Default)]
+pub struct Heap {
+    buf: Vec<u8>,
+    leases: usize,
+}
+
+#[derive(Default)]
+pub struct Stack {
+    buf: [u8; 4],
+    leases: usize,
+}
+
+pub struct HeapGuard<'a>(&'a mut Vec<u8>);
+
+impl Deref for HeapGuard<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl DerefMut for HeapGuard<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.0
+    }
+}
+
+impl Lease for Heap {
+    type Guard<'a> = HeapGuard<'a>;
+
+    fn lease(&mut self) -> HeapGuard<'_> {
+        self.leases += 1;
+        self.buf.resize(8, 0);
+        HeapGuard(&mut self.buf)
+    }
+
+    fn leased(&self) -> usize {
+        self.leases
+    }
+}
+
+impl Lease for Stack {
+    type Guard<'a> = &'a mut [u8];
+
+    fn lease(&mut self) -> &mut [u8] {
+        self.leases += 1;
+        &mut self.buf
+    }
+
+    fn leased(&self) -> usize {
+        self.leases
+    }
+}
+
+#[test]
+fn lease_wraps_each_guard() {
+    let mut pool = Pool::Heap(Heap::default());
+    let guard = pool.lease();
+    assert!(matches!(guard, PoolGuard::Heap(_)));
+    assert_eq!(guard.len(), 8);
+
+    let mut pool = Pool::Stack(Stack::default());
+    assert!(matches!(pool.lease(), PoolGuard::Stack(_)));
+    assert_eq!(pool.lease().len(), 4);
+    assert_eq!(pool.leased(), 2);
+}
+
+#[test]
+fn guard_forwards_deref_mut() {
+    for mut pool in [Pool::Heap(Heap::default()), Pool::Stack(Stack::default())] {
+        {
+            let mut guard = pool.lease();
+            guard[0] = 42;
+        }
+        assert_eq!(pool.lease()[0], 42);
+    }
+}
+
+fn first<L: Lease>(lease: &mut L) -> u8 {
+    lease.lease()[0]
+}
+
+#[test]
+fn enum_implements_lease() {
+    let mut pool = Pool::Stack(Stack::default());
+    pool.lease()[0] = 7;
+    assert_eq!(first(&mut pool), 7);
+}
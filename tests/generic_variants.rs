@@ -0,0 +1,72 @@
+disponent::declare!(
+    pub enum OneOf<A, B> {
+        A(A),
+        B(B),
+        Fixed(Fixed),
+    }
+
+    pub trait Describe {
+        fn describe(&self) -> String;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub))]
+    pub enum InherentOneOf<T, const N: usize> {
+        Many(Many<T, N>),
+        Fixed(Fixed),
+    }
+
+    pub trait InherentDescribe {
+        fn describe(&self) -> String;
+    }
+);
+
+pub struct Fixed;
+
+pub struct Many<T, const N: usize>([T; N]);
+
+impl Describe for Fixed {
+    fn describe(&self) -> String {
+        "fixed".into()
+    }
+}
+
+impl Describe for u8 {
+    fn describe(&self) -> String {
+        format!("u8 {self}")
+    }
+}
+
+impl Describe for &str {
+    fn describe(&self) -> String {
+        format!("str {self}")
+    }
+}
+
+impl InherentDescribe for Fixed {
+    fn describe(&self) -> String {
+        "fixed".into()
+    }
+}
+
+impl<T: std::fmt::Debug, const N: usize> InherentDescribe for Many<T, N> {
+    fn describe(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+#[test]
+fn test_inferred_bounds() {
+    let values: [OneOf<u8, &str>; 3] = [OneOf::A(1), OneOf::B("b"), OneOf::Fixed(Fixed)];
+    let described: Vec<_> = values.iter().map(Describe::describe).collect();
+    assert_eq!(described, ["u8 1", "str b", "fixed"]);
+}
+
+#[test]
+fn test_inferred_bounds_inherent() {
+    let many: InherentOneOf<u8, 2> = InherentOneOf::Many(Many([1, 2]));
+    assert_eq!(many.describe(), "[1, 2]");
+    let fixed: InherentOneOf<u8, 2> = InherentOneOf::Fixed(Fixed);
+    assert_eq!(fixed.describe(), "fixed");
+}
@@ -0,0 +1,167 @@
+use std::cell::RefCell;
+
+thread_local! {
+    static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_calls() -> Vec<String> {
+    CALLS.with(|calls| calls.take())
+}
+
+fn record(call: String) {
+    CALLS.with(|calls| calls.borrow_mut().push(call));
+}
+
+pub struct Recorder;
+
+impl Recorder {
+    pub fn before(variant: &'static str, method: &'static str) {
+        record(format!("before {variant}::{method}"));
+    }
+
+    pub fn after(variant: &'static str, method: &'static str) {
+        record(format!("after {variant}::{method}"));
+    }
+}
+
+disponent::declare!(
+    #[disponent::configure(hook = Recorder)]
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Greet {
+        fn greet(&self) -> &'static str;
+
+        #[allow(async_fn_in_trait)]
+        async fn greet_async(&self) -> &'static str;
+    }
+);
+
+pub struct AsyncRecorder;
+
+impl AsyncRecorder {
+    pub async fn before(variant: &'static str, method: &'static str) {
+        record(format!("async before {variant}::{method}"));
+    }
+
+    pub async fn after(variant: &'static str, method: &'static str) {
+        record(format!("async after {variant}::{method}"));
+    }
+}
+
+disponent::declare!(
+    #[disponent::configure(hook = Recorder, async_hook = AsyncRecorder)]
+    pub enum AsyncFooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    #[disponent::remote(Greet)]
+    pub trait AsyncGreet {
+        fn greet(&self) -> &'static str;
+
+        #[allow(async_fn_in_trait)]
+        async fn greet_async(&self) -> &'static str;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent, hook = Recorder, dispatch = "table")]
+    pub enum InherentFooOrBar {
+        Foo(Foo),
+    }
+
+    pub trait InherentGreet {
+        fn greet(&self) -> &'static str;
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+
+impl Greet for Foo {
+    fn greet(&self) -> &'static str {
+        record("Foo::greet".into());
+        "foo"
+    }
+
+    async fn greet_async(&self) -> &'static str {
+        record("Foo::greet_async".into());
+        "foo"
+    }
+}
+
+impl Greet for Bar {
+    fn greet(&self) -> &'static str {
+        "bar"
+    }
+
+    async fn greet_async(&self) -> &'static str {
+        "bar"
+    }
+}
+
+impl InherentGreet for Foo {
+    fn greet(&self) -> &'static str {
+        "foo"
+    }
+}
+
+#[test]
+fn test_hooks() {
+    assert_eq!(FooOrBar::Foo(Foo).greet(), "foo");
+    assert_eq!(
+        take_calls(),
+        ["before Foo::greet", "Foo::greet", "after Foo::greet"]
+    );
+
+    assert_eq!(FooOrBar::Bar(Bar).greet(), "bar");
+    assert_eq!(take_calls(), ["before Bar::greet", "after Bar::greet"]);
+}
+
+#[test]
+fn test_async_hooks() {
+    let foo = FooOrBar::Foo(Foo);
+    let future = foo.greet_async();
+    // Nothing runs until the future is polled
+    assert!(take_calls().is_empty());
+
+    assert_eq!(smol::block_on(future), "foo");
+    assert_eq!(
+        take_calls(),
+        [
+            "before Foo::greet_async",
+            "Foo::greet_async",
+            "after Foo::greet_async"
+        ]
+    );
+}
+
+#[test]
+fn test_async_hook() {
+    let foo = AsyncFooOrBar::Foo(Foo);
+    assert_eq!(smol::block_on(foo.greet_async()), "foo");
+    assert_eq!(
+        take_calls(),
+        [
+            "async before Foo::greet_async",
+            "Foo::greet_async",
+            "async after Foo::greet_async"
+        ]
+    );
+
+    // Other methods still use `hook`
+    assert_eq!(foo.greet(), "foo");
+    assert_eq!(
+        take_calls(),
+        ["before Foo::greet", "Foo::greet", "after Foo::greet"]
+    );
+}
+
+#[test]
+fn test_inherent_table_hooks() {
+    assert_eq!(InherentFooOrBar::Foo(Foo).greet(), "foo");
+    assert_eq!(take_calls(), ["before Foo::greet", "after Foo::greet"]);
+}
@@ -0,0 +1,111 @@
+use std::{fmt::Display, ops::DerefMut};
+
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Bytes {
+        fn bytes(&self) -> impl Iterator<Item = u8>;
+        fn names<'a>(&'a self, prefix: &'a str) -> impl DoubleEndedIterator<Item = &'a str> + 'a;
+        fn label(&self) -> impl Display;
+        fn buffer(&mut self) -> impl DerefMut<Target = [u8]>;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent)]
+    pub enum InherentFooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait InherentBytes {
+        fn bytes(&self) -> impl ExactSizeIterator<Item = u8>;
+    }
+);
+
+pub struct Foo;
+
+impl Bytes for Foo {
+    fn bytes(&self) -> impl Iterator<Item = u8> {
+        [1, 2, 3].into_iter()
+    }
+    fn names<'a>(&'a self, prefix: &'a str) -> impl DoubleEndedIterator<Item = &'a str> + 'a {
+        [prefix, "foo"].into_iter()
+    }
+    fn label(&self) -> impl Display {
+        "foo"
+    }
+    fn buffer(&mut self) -> impl DerefMut<Target = [u8]> {
+        vec![1, 2, 3]
+    }
+}
+
+impl InherentBytes for Foo {
+    fn bytes(&self) -> impl ExactSizeIterator<Item = u8> {
+        vec![1, 2, 3].into_iter()
+    }
+}
+
+pub struct Bar;
+
+impl Bytes for Bar {
+    fn bytes(&self) -> impl Iterator<Item = u8> {
+        (4..6).map(|b| b * 2)
+    }
+    fn names<'a>(&'a self, prefix: &'a str) -> impl DoubleEndedIterator<Item = &'a str> + 'a {
+        std::iter::once(prefix)
+    }
+    fn label(&self) -> impl Display {
+        42
+    }
+    fn buffer(&mut self) -> impl DerefMut<Target = [u8]> {
+        vec![4, 5].into_boxed_slice()
+    }
+}
+
+impl InherentBytes for Bar {
+    fn bytes(&self) -> impl ExactSizeIterator<Item = u8> {
+        [4, 5].into_iter()
+    }
+}
+
+#[test]
+fn test_iterator() {
+    assert_eq!(FooOrBar::Foo(Foo).bytes().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(FooOrBar::Bar(Bar).bytes().collect::<Vec<_>>(), [8, 10]);
+}
+
+#[test]
+fn test_double_ended_iterator() {
+    let foo = FooOrBar::Foo(Foo);
+    assert_eq!(foo.names("a").rev().collect::<Vec<_>>(), ["foo", "a"]);
+
+    let bar = FooOrBar::Bar(Bar);
+    assert_eq!(bar.names("b").next_back(), Some("b"));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(FooOrBar::Foo(Foo).label().to_string(), "foo");
+    assert_eq!(FooOrBar::Bar(Bar).label().to_string(), "42");
+}
+
+#[test]
+fn test_deref_mut() {
+    let mut foo = FooOrBar::Foo(Foo);
+    let mut buffer = foo.buffer();
+    buffer[0] = 7;
+    assert_eq!(*buffer, [7, 2, 3]);
+
+    let mut bar = FooOrBar::Bar(Bar);
+    assert_eq!(bar.buffer().len(), 2);
+}
+
+#[test]
+fn test_inherent_exact_size_iterator() {
+    assert_eq!(InherentFooOrBar::Foo(Foo).bytes().len(), 3);
+    assert_eq!(InherentFooOrBar::Bar(Bar).bytes().len(), 2);
+}
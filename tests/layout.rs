@@ -0,0 +1,38 @@
+disponent::declare!(
+    #[disponent::configure(size_lint(max = 64, ratio = 32), layout)]
+    pub enum Storage {
+        Small(Small),
+        r#Large(Large),
+        #[cfg(any())]
+        Huge(Huge),
+    }
+
+    pub trait Capacity {
+        fn capacity(&self) -> usize;
+    }
+);
+
+pub struct Small(u8);
+pub struct Large([u64; 4]);
+pub struct Huge(pub [u8; 4096]);
+
+impl Capacity for Small {
+    fn capacity(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Capacity for Large {
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[test]
+fn test_layout() {
+    assert_eq!(
+        Storage::LAYOUT,
+        [("Small", 1, 1), ("Large", 32, 8)].as_slice()
+    );
+    assert_eq!(Storage::Small(Small(3)).capacity(), 3);
+}
@@ -0,0 +1,124 @@
+use std::panic::Location;
+
+disponent::declare!(
+    #[disponent::configure(inline(always))]
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Locate {
+        #[track_caller]
+        fn location(&self) -> &'static Location<'static>;
+
+        #[must_use]
+        #[disponent::inline(never)]
+        fn name(&self) -> &'static str;
+
+        #[cold]
+        #[inline(never)]
+        fn fail(&self) -> bool {
+            false
+        }
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub), inline)]
+    pub enum InherentFooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait InherentLocate {
+        #[track_caller]
+        fn location(&self) -> &'static Location<'static>;
+
+        #[deprecated = "use something else"]
+        fn old(&self) -> u32 {
+            1
+        }
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(dispatch = "table")]
+    pub enum TableFooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    #[disponent::remote(Locate)]
+    pub trait TableLocate {
+        #[track_caller]
+        fn location(&self) -> &'static Location<'static>;
+        fn name(&self) -> &'static str;
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+
+impl Locate for Foo {
+    #[track_caller]
+    fn location(&self) -> &'static Location<'static> {
+        Location::caller()
+    }
+    fn name(&self) -> &'static str {
+        "Foo"
+    }
+}
+
+impl Locate for Bar {
+    #[track_caller]
+    fn location(&self) -> &'static Location<'static> {
+        Location::caller()
+    }
+    fn name(&self) -> &'static str {
+        "Bar"
+    }
+}
+
+impl InherentLocate for Foo {
+    #[track_caller]
+    fn location(&self) -> &'static Location<'static> {
+        Location::caller()
+    }
+}
+
+impl InherentLocate for Bar {
+    #[track_caller]
+    fn location(&self) -> &'static Location<'static> {
+        Location::caller()
+    }
+}
+
+#[test]
+fn test_track_caller() {
+    let foo = FooOrBar::Foo(Foo);
+    let expected = line!() + 1;
+    let location = foo.location();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.line(), expected);
+
+    let bar = InherentFooOrBar::Bar(Bar);
+    let expected = line!() + 1;
+    let location = bar.location();
+    assert_eq!(location.line(), expected);
+
+    let table = TableFooOrBar::Bar(Bar);
+    let expected = line!() + 1;
+    let location = Locate::location(&table);
+    assert_eq!(location.line(), expected);
+}
+
+#[test]
+fn test_other_attributes() {
+    let foo = FooOrBar::Foo(Foo);
+    assert_eq!(foo.name(), "Foo");
+    assert!(!foo.fail());
+
+    #[allow(deprecated)]
+    let old = InherentFooOrBar::Foo(Foo).old();
+    assert_eq!(old, 1);
+}
@@ -0,0 +1,95 @@
+disponent::declare!(
+    #[disponent::configure(from)]
+    #[derive(Debug, PartialEq)]
+    pub enum Local {
+        Memory(Memory),
+        Disk(Disk),
+    }
+
+    pub trait Store {
+        fn get(&self) -> u32;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(from, try_into)]
+    #[derive(Debug, PartialEq)]
+    pub enum Backend {
+        #[disponent::nested(Memory(Memory), Disk(Disk))]
+        Local(Local),
+        Remote(Remote),
+    }
+
+    pub trait AnyStore {
+        fn get(&self) -> u32;
+    }
+);
+
+#[derive(Debug, PartialEq)]
+pub struct Memory(u32);
+
+#[derive(Debug, PartialEq)]
+pub struct Disk(u32);
+
+#[derive(Debug, PartialEq)]
+pub struct Remote;
+
+impl Store for Memory {
+    fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Store for Disk {
+    fn get(&self) -> u32 {
+        self.0 * 10
+    }
+}
+
+impl AnyStore for Local {
+    fn get(&self) -> u32 {
+        Store::get(self)
+    }
+}
+
+impl AnyStore for Remote {
+    fn get(&self) -> u32 {
+        0
+    }
+}
+
+#[test]
+fn test_transitive_from() {
+    assert_eq!(
+        Backend::from(Memory(1)),
+        Backend::Local(Local::Memory(Memory(1)))
+    );
+    assert_eq!(Backend::from(Disk(2)).get(), 20);
+    assert_eq!(Backend::from(Local::from(Disk(2))).get(), 20);
+    assert_eq!(Backend::from(Remote), Backend::Remote(Remote));
+}
+
+#[test]
+fn test_transitive_try_into() {
+    let memory: Result<Memory, _> = Backend::from(Memory(3)).try_into();
+    assert_eq!(memory.unwrap(), Memory(3));
+
+    let disk: Result<Disk, _> = Backend::from(Memory(3)).try_into();
+    assert!(disk.is_err());
+
+    let local: Result<Local, _> = Backend::from(Disk(4)).try_into();
+    assert_eq!(local.unwrap(), Local::Disk(Disk(4)));
+}
+
+#[test]
+fn test_nested_accessors() {
+    let mut backend = Backend::from(Memory(5));
+    assert_eq!(backend.as_memory(), Some(&Memory(5)));
+    assert_eq!(backend.as_disk(), None);
+
+    if let Some(memory) = backend.as_memory_mut() {
+        memory.0 = 6;
+    }
+    assert_eq!(backend.get(), 6);
+    assert_eq!(Backend::Remote(Remote).as_memory(), None);
+}
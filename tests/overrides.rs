@@ -0,0 +1,93 @@
+disponent::declare!(
+    pub enum Backend {
+        Disk(Disk),
+        #[disponent::override_with(flush = skip_flush)]
+        Legacy(Legacy),
+    }
+
+    pub trait Storage {
+        fn flush(&mut self) -> usize;
+
+        #[disponent::override_with(Legacy => |legacy: &Legacy, scale: u32| legacy.0 * scale)]
+        fn size(&self, scale: u32) -> u32;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub), dispatch = "table")]
+    pub enum InherentBackend {
+        #[disponent::override_with(flush = skip_flush)]
+        Legacy(Legacy),
+        Disk(Disk),
+    }
+
+    pub trait InherentStorage {
+        fn flush(&mut self) -> usize;
+    }
+);
+
+pub struct Disk(usize);
+pub struct Legacy(u32);
+
+impl Storage for Disk {
+    fn flush(&mut self) -> usize {
+        std::mem::take(&mut self.0)
+    }
+
+    fn size(&self, scale: u32) -> u32 {
+        self.0 as u32 * scale
+    }
+}
+
+impl Storage for Legacy {
+    fn flush(&mut self) -> usize {
+        panic!("legacy backends can't flush")
+    }
+
+    fn size(&self, _scale: u32) -> u32 {
+        panic!("legacy backends don't know their size")
+    }
+}
+
+impl InherentStorage for Disk {
+    fn flush(&mut self) -> usize {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl InherentStorage for Legacy {
+    fn flush(&mut self) -> usize {
+        panic!("legacy backends can't flush")
+    }
+}
+
+fn skip_flush(_: &mut Legacy) -> usize {
+    0
+}
+
+#[test]
+fn test_variant_override() {
+    let mut disk = Backend::Disk(Disk(4));
+    let mut legacy = Backend::Legacy(Legacy(2));
+
+    assert_eq!(disk.flush(), 4);
+    assert_eq!(legacy.flush(), 0);
+}
+
+#[test]
+fn test_method_override() {
+    let disk = Backend::Disk(Disk(4));
+    let legacy = Backend::Legacy(Legacy(2));
+
+    assert_eq!(disk.size(3), 12);
+    assert_eq!(legacy.size(3), 6);
+}
+
+#[test]
+fn test_inherent_override() {
+    let mut disk = InherentBackend::Disk(Disk(4));
+    let mut legacy = InherentBackend::Legacy(Legacy(2));
+
+    assert_eq!(disk.flush(), 4);
+    assert_eq!(legacy.flush(), 0);
+}
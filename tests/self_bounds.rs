@@ -0,0 +1,108 @@
+disponent::declare!(
+    // Trait impls can only require `T: Clone` for `dup` if the enum does
+    #[derive(Clone)]
+    pub enum Buffer<T: Source + Clone> {
+        Inline(Inline),
+        Custom(T),
+    }
+
+    pub trait Source {
+        fn read(&self) -> Vec<u8>;
+
+        fn dup(&self) -> Vec<u8>
+        where
+            Self: Clone,
+        {
+            self.clone().read()
+        }
+
+        fn into_bytes(self) -> Vec<u8>
+        where
+            Self: Sized,
+        {
+            self.read()
+        }
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub))]
+    pub enum InherentBuffer {
+        Inline(Inline),
+        Shared(Shared),
+    }
+
+    pub trait InherentSource {
+        fn read(&self) -> Vec<u8>;
+
+        fn dup(&self) -> Vec<u8>
+        where
+            Self: Clone + Send,
+        {
+            self.clone().read()
+        }
+
+        fn into_bytes(self) -> Vec<u8>
+        where
+            Self: Sized;
+    }
+);
+
+#[derive(Clone)]
+pub struct Inline([u8; 2]);
+
+#[derive(Clone)]
+pub struct Shared(std::sync::Arc<Vec<u8>>);
+
+impl Source for Inline {
+    fn read(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+impl Source for Shared {
+    fn read(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+impl InherentSource for Inline {
+    fn read(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.0.into()
+    }
+}
+
+impl InherentSource for Shared {
+    fn read(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        std::sync::Arc::unwrap_or_clone(self.0)
+    }
+}
+
+#[test]
+fn test_self_bounds_on_generic_variants() {
+    let inline: Buffer<Shared> = Buffer::Inline(Inline([1, 2]));
+    let custom: Buffer<Shared> = Buffer::Custom(Shared(vec![3].into()));
+    assert_eq!(inline.dup(), vec![1, 2]);
+    assert_eq!(custom.dup(), vec![3]);
+    assert_eq!(custom.into_bytes(), vec![3]);
+}
+
+#[test]
+fn test_self_bounds_inherent() {
+    // The enum doesn't implement `Clone`, but every variant does
+    let shared = InherentBuffer::Shared(Shared(vec![4, 5].into()));
+    assert_eq!(shared.dup(), vec![4, 5]);
+    assert_eq!(shared.into_bytes(), vec![4, 5]);
+    assert_eq!(
+        InherentBuffer::Inline(Inline([6, 7])).into_bytes(),
+        vec![6, 7]
+    );
+}
@@ -0,0 +1,116 @@
+disponent::declare!(
+    #[derive(Debug, PartialEq)]
+    pub enum Shape {
+        Circle(Circle),
+        Square(Square),
+    }
+
+    pub trait Scale {
+        fn scaled(&self, factor: u32) -> Self;
+        fn area(&self) -> u32;
+
+        #[disponent::skip]
+        fn larger<'a>(&'a self, other: &'a Self) -> &'a Self {
+            if other.area() > self.area() {
+                other
+            } else {
+                self
+            }
+        }
+
+        #[disponent::skip]
+        fn largest(shapes: &[Self]) -> Option<&Self>
+        where
+            Self: Sized,
+        {
+            shapes.iter().max_by_key(|s| s.area())
+        }
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub))]
+    #[derive(Debug, PartialEq)]
+    pub enum InherentShape {
+        Circle(Circle),
+        Square(Square),
+    }
+
+    #[allow(async_fn_in_trait)]
+    pub trait InherentScale {
+        fn scaled(&self, factor: u32) -> Self;
+        async fn scaled_async(&self, factor: u32) -> Self;
+    }
+);
+
+#[derive(Debug, PartialEq)]
+pub struct Circle(u32);
+
+#[derive(Debug, PartialEq)]
+pub struct Square(u32);
+
+impl Scale for Circle {
+    fn scaled(&self, factor: u32) -> Self {
+        Circle(self.0 * factor)
+    }
+
+    fn area(&self) -> u32 {
+        3 * self.0 * self.0
+    }
+}
+
+impl Scale for Square {
+    fn scaled(&self, factor: u32) -> Self {
+        Square(self.0 * factor)
+    }
+
+    fn area(&self) -> u32 {
+        self.0 * self.0
+    }
+}
+
+impl InherentScale for Circle {
+    fn scaled(&self, factor: u32) -> Self {
+        Circle(self.0 * factor)
+    }
+
+    async fn scaled_async(&self, factor: u32) -> Self {
+        Circle(self.0 * factor)
+    }
+}
+
+impl InherentScale for Square {
+    fn scaled(&self, factor: u32) -> Self {
+        Square(self.0 * factor)
+    }
+
+    async fn scaled_async(&self, factor: u32) -> Self {
+        Square(self.0 * factor)
+    }
+}
+
+#[test]
+fn test_self_return_is_wrapped() {
+    assert_eq!(Shape::Circle(Circle(1)).scaled(2), Shape::Circle(Circle(2)));
+    assert_eq!(Shape::Square(Square(3)).scaled(2), Shape::Square(Square(6)));
+}
+
+#[test]
+fn test_self_in_skipped_methods() {
+    let circle = Shape::Circle(Circle(1));
+    let square = Shape::Square(Square(2));
+    assert_eq!(circle.larger(&square), &square);
+
+    let shapes = [circle, square, Shape::Square(Square(1))];
+    assert_eq!(Shape::largest(&shapes), Some(&Shape::Square(Square(2))));
+}
+
+#[test]
+fn test_self_return_inherent() {
+    let circle = InherentShape::Circle(Circle(1));
+    assert_eq!(circle.scaled(2), InherentShape::Circle(Circle(2)));
+    assert_eq!(
+        smol::block_on(circle.scaled_async(3)),
+        InherentShape::Circle(Circle(3))
+    );
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+
+disponent::declare!(
+    #[disponent::configure(serde)]
+    #[derive(Debug, PartialEq)]
+    pub enum External {
+        Memory(Memory),
+        #[disponent::name("redis")]
+        Redis(Redis),
+        #[cfg(any())]
+        Disabled(Memory),
+    }
+
+    pub trait Cache {
+        fn describe(&self) -> String;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(serde(tag = "type"))]
+    #[derive(Debug, PartialEq)]
+    pub enum Internal {
+        Memory(Memory),
+        #[disponent::name("redis")]
+        Redis(Redis),
+    }
+
+    pub trait InternalCache {}
+);
+
+disponent::declare!(
+    #[disponent::configure(serde(tag = "type", content = "config"))]
+    #[derive(Debug, PartialEq)]
+    pub enum Adjacent {
+        Memory(Memory),
+        #[disponent::name("redis")]
+        Redis(Redis),
+    }
+
+    pub trait AdjacentCache {}
+);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Memory {
+    capacity: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Redis {
+    url: String,
+}
+
+impl Cache for Memory {
+    fn describe(&self) -> String {
+        format!("memory ({})", self.capacity)
+    }
+}
+
+impl Cache for Redis {
+    fn describe(&self) -> String {
+        format!("redis at {}", self.url)
+    }
+}
+
+impl InternalCache for Memory {}
+impl InternalCache for Redis {}
+impl AdjacentCache for Memory {}
+impl AdjacentCache for Redis {}
+
+fn redis() -> Redis {
+    Redis {
+        url: "redis://localhost".into(),
+    }
+}
+
+#[test]
+fn test_externally_tagged() {
+    let cache = External::Redis(redis());
+    let json = serde_json::to_string(&cache).unwrap();
+    assert_eq!(json, r#"{"redis":{"url":"redis://localhost"}}"#);
+
+    let cache: External = serde_json::from_str(r#"{"Memory":{"capacity":16}}"#).unwrap();
+    assert_eq!(cache.describe(), "memory (16)");
+}
+
+#[test]
+fn test_internally_tagged() {
+    let cache = Internal::Redis(redis());
+    let json = serde_json::to_string(&cache).unwrap();
+    assert_eq!(json, r#"{"type":"redis","url":"redis://localhost"}"#);
+    assert_eq!(serde_json::from_str::<Internal>(&json).unwrap(), cache);
+}
+
+#[test]
+fn test_adjacently_tagged() {
+    let cache = Adjacent::Memory(Memory { capacity: 16 });
+    let json = serde_json::to_string(&cache).unwrap();
+    assert_eq!(json, r#"{"type":"Memory","config":{"capacity":16}}"#);
+    assert_eq!(serde_json::from_str::<Adjacent>(&json).unwrap(), cache);
+}
+
+#[test]
+fn test_unknown_tag() {
+    let error = serde_json::from_str::<Internal>(r#"{"type":"disk"}"#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown variant `disk`, expected `Memory` or `redis` at line 1 column 14"
+    );
+
+    // `#[cfg]`'d out variants aren't valid tags
+    let error = serde_json::from_str::<External>(r#"{"Disabled":{"capacity":16}}"#).unwrap_err();
+    assert!(error.to_string().starts_with("unknown variant `Disabled`"));
+}
@@ -0,0 +1,92 @@
+disponent::declare!(
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Greet {
+        fn name(&self) -> String;
+
+        #[disponent::skip]
+        fn greeting(&self) -> String {
+            format!("Hello, {}!", self.name())
+        }
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub))]
+    pub enum InherentFooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait InherentGreet {
+        fn name(&self) -> String;
+
+        #[disponent::skip]
+        fn greeting(&self) -> String {
+            format!("Hello, {}!", self.name())
+        }
+
+        #[disponent::skip]
+        #[allow(async_fn_in_trait)]
+        async fn greeting_async(&self) -> String {
+            self.greeting()
+        }
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+
+impl Greet for Foo {
+    fn name(&self) -> String {
+        "Foo".into()
+    }
+
+    fn greeting(&self) -> String {
+        "Foo says hi".into()
+    }
+}
+
+impl Greet for Bar {
+    fn name(&self) -> String {
+        "Bar".into()
+    }
+}
+
+impl InherentGreet for Foo {
+    fn name(&self) -> String {
+        "Foo".into()
+    }
+
+    fn greeting(&self) -> String {
+        "Foo says hi".into()
+    }
+}
+
+impl InherentGreet for Bar {
+    fn name(&self) -> String {
+        "Bar".into()
+    }
+}
+
+#[test]
+fn test_skip_uses_default() {
+    // `Foo` overrides `greeting`, but the enum runs the trait's default body instead
+    assert_eq!(FooOrBar::Foo(Foo).greeting(), "Hello, Foo!");
+    assert_eq!(FooOrBar::Bar(Bar).greeting(), "Hello, Bar!");
+}
+
+#[test]
+fn test_skip_inherent() {
+    assert_eq!(InherentFooOrBar::Foo(Foo).greeting(), "Hello, Foo!");
+    assert_eq!(InherentFooOrBar::Bar(Bar).greeting(), "Hello, Bar!");
+}
+
+#[test]
+fn test_skip_inherent_async() {
+    let foo = InherentFooOrBar::Foo(Foo);
+    assert_eq!(smol::block_on(foo.greeting_async()), "Hello, Foo!");
+}
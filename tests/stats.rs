@@ -0,0 +1,95 @@
+disponent::declare!(
+    #[disponent::configure(stats)]
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+        #[cfg(any())]
+        Baz(Baz),
+    }
+
+    pub trait Greet {
+        fn greet(&self) -> &'static str;
+        fn shout(&mut self) -> &'static str;
+
+        #[disponent::skip]
+        fn greet_twice(&self) -> [&'static str; 2] {
+            [self.greet(), self.greet()]
+        }
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub), stats, dispatch = "table")]
+    pub enum Shape<T: Into<u32> + Copy> {
+        Square(Square<T>),
+    }
+
+    pub trait Area {
+        fn area(&self) -> u32;
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+pub struct Square<T>(T);
+
+impl Greet for Foo {
+    fn greet(&self) -> &'static str {
+        "hello"
+    }
+
+    fn shout(&mut self) -> &'static str {
+        "HELLO"
+    }
+}
+
+impl Greet for Bar {
+    fn greet(&self) -> &'static str {
+        "hi"
+    }
+
+    fn shout(&mut self) -> &'static str {
+        "HI"
+    }
+}
+
+impl<T: Into<u32> + Copy> Area for Square<T> {
+    fn area(&self) -> u32 {
+        self.0.into() * self.0.into()
+    }
+}
+
+#[test]
+fn test_stats() {
+    let mut foo = FooOrBar::Foo(Foo);
+    let bar = FooOrBar::Bar(Bar);
+
+    assert_eq!(foo.greet_twice(), ["hello", "hello"]);
+    foo.shout();
+    bar.greet();
+
+    assert_eq!(
+        FooOrBar::dispatch_stats().collect::<Vec<_>>(),
+        [
+            ("Foo", "greet", 2),
+            ("Foo", "shout", 1),
+            ("Bar", "greet", 1),
+            ("Bar", "shout", 0),
+        ]
+    );
+
+    FooOrBar::reset_stats();
+    assert!(FooOrBar::dispatch_stats().all(|(_, _, calls)| calls == 0));
+}
+
+#[test]
+fn test_generic_stats() {
+    let square = Shape::Square(Square(3u8));
+    assert_eq!(square.area(), 9);
+    assert_eq!(square.area(), 9);
+
+    assert_eq!(
+        Shape::<u8>::dispatch_stats().collect::<Vec<_>>(),
+        [("Square", "area", 2)]
+    );
+}
@@ -0,0 +1,102 @@
+mod storage {
+    pub trait Flush {
+        fn flush(&mut self) -> usize;
+    }
+}
+
+use storage::Flush;
+
+disponent::declare!(
+    #[derive(Clone)]
+    pub enum Backend {
+        Disk(Disk),
+        Memory(Memory),
+    }
+
+    #[disponent::supertrait(storage::Flush {
+        fn flush(&mut self) -> usize;
+    })]
+    pub trait Store: Named + Flush + Clone + Send {
+        fn get(&self, key: &str) -> Option<String>;
+    }
+
+    pub trait Named: Describe {
+        fn name(&self) -> &'static str;
+    }
+
+    pub trait Describe {
+        fn describe(&self) -> String;
+    }
+);
+
+#[derive(Clone, Default)]
+pub struct Disk(Vec<String>);
+
+#[derive(Clone, Default)]
+pub struct Memory;
+
+impl Store for Disk {
+    fn get(&self, key: &str) -> Option<String> {
+        self.0.iter().find(|k| *k == key).cloned()
+    }
+}
+
+impl Store for Memory {
+    fn get(&self, _key: &str) -> Option<String> {
+        None
+    }
+}
+
+impl Named for Disk {
+    fn name(&self) -> &'static str {
+        "disk"
+    }
+}
+
+impl Named for Memory {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+}
+
+impl Describe for Disk {
+    fn describe(&self) -> String {
+        format!("disk with {} keys", self.0.len())
+    }
+}
+
+impl Describe for Memory {
+    fn describe(&self) -> String {
+        "memory".into()
+    }
+}
+
+impl Flush for Disk {
+    fn flush(&mut self) -> usize {
+        std::mem::take(&mut self.0).len()
+    }
+}
+
+impl Flush for Memory {
+    fn flush(&mut self) -> usize {
+        0
+    }
+}
+
+fn describe_store(store: &impl Store) -> String {
+    format!("{}: {}", store.name(), store.describe())
+}
+
+#[test]
+fn test_supertraits() {
+    let mut disk = Backend::Disk(Disk(vec!["a".into(), "b".into()]));
+
+    assert_eq!(disk.get("a").as_deref(), Some("a"));
+    assert_eq!(describe_store(&disk), "disk: disk with 2 keys");
+    assert_eq!(disk.clone().flush(), 2);
+    assert_eq!(disk.flush(), 2);
+    assert_eq!(disk.flush(), 0);
+
+    let memory = Backend::Memory(Memory);
+    assert_eq!(describe_store(&memory), "memory: memory");
+}
@@ -0,0 +1,158 @@
+disponent::declare!(
+    #[disponent::configure(dispatch = "table")]
+    pub enum Shape {
+        Circle(Circle),
+        Square(Square),
+        #[cfg(any())]
+        Never(Circle),
+    }
+
+    pub trait Area {
+        fn area(&self) -> f64;
+        fn name(&self) -> &str;
+        fn scale(&mut self, factor: f64);
+        fn pick<'a>(&self, a: &'a str, b: &'a str) -> &'a str;
+        fn into_label(self, prefix: String) -> String;
+        fn describe<T: std::fmt::Display>(&self, val: T) -> String;
+        fn compute(&self) -> impl Future<Output = f64>;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent, dispatch = "table")]
+    pub enum InherentShape {
+        Circle(Circle),
+        Square(Square),
+    }
+
+    pub trait InherentArea {
+        fn area(&self) -> f64;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(dispatch = "table")]
+    pub enum ScaledShape {
+        Circle(Circle),
+        Square(Square),
+    }
+
+    pub trait Scaled<T> {
+        fn scaled(&self, factor: T) -> f64;
+    }
+);
+
+pub struct Circle(f64);
+pub struct Square(f64);
+
+impl Area for Circle {
+    fn area(&self) -> f64 {
+        3.0 * self.0 * self.0
+    }
+    fn name(&self) -> &str {
+        "circle"
+    }
+    fn scale(&mut self, factor: f64) {
+        self.0 *= factor;
+    }
+    fn pick<'a>(&self, a: &'a str, _b: &'a str) -> &'a str {
+        a
+    }
+    fn into_label(self, prefix: String) -> String {
+        format!("{prefix} circle")
+    }
+    fn describe<T: std::fmt::Display>(&self, val: T) -> String {
+        format!("circle {val}")
+    }
+    async fn compute(&self) -> f64 {
+        Area::area(self)
+    }
+}
+
+impl Area for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+    fn name(&self) -> &str {
+        "square"
+    }
+    fn scale(&mut self, factor: f64) {
+        self.0 *= factor;
+    }
+    fn pick<'a>(&self, _a: &'a str, b: &'a str) -> &'a str {
+        b
+    }
+    fn into_label(self, prefix: String) -> String {
+        format!("{prefix} square")
+    }
+    fn describe<T: std::fmt::Display>(&self, val: T) -> String {
+        format!("square {val}")
+    }
+    async fn compute(&self) -> f64 {
+        Area::area(self)
+    }
+}
+
+impl InherentArea for Circle {
+    fn area(&self) -> f64 {
+        Area::area(self)
+    }
+}
+
+impl InherentArea for Square {
+    fn area(&self) -> f64 {
+        Area::area(self)
+    }
+}
+
+impl<T: Into<f64>> Scaled<T> for Circle {
+    fn scaled(&self, factor: T) -> f64 {
+        Area::area(self) * factor.into()
+    }
+}
+
+impl<T: Into<f64>> Scaled<T> for Square {
+    fn scaled(&self, factor: T) -> f64 {
+        Area::area(self) * factor.into()
+    }
+}
+
+#[test]
+fn test_table_dispatch() {
+    let mut circle = Shape::Circle(Circle(1.0));
+    let mut square = Shape::Square(Square(2.0));
+
+    assert_eq!(circle.area(), 3.0);
+    assert_eq!(square.area(), 4.0);
+    assert_eq!(circle.name(), "circle");
+    assert_eq!(square.name(), "square");
+    assert_eq!(circle.pick("a", "b"), "a");
+    assert_eq!(square.pick("a", "b"), "b");
+
+    circle.scale(2.0);
+    square.scale(0.5);
+    assert_eq!(circle.area(), 12.0);
+    assert_eq!(square.area(), 1.0);
+
+    assert_eq!(circle.into_label("big".to_string()), "big circle");
+    assert_eq!(square.into_label("small".to_string()), "small square");
+}
+
+#[test]
+fn test_table_dispatch_fallback() {
+    let circle = Shape::Circle(Circle(1.0));
+    assert_eq!(circle.describe(1), "circle 1");
+    assert_eq!(smol::block_on(circle.compute()), 3.0);
+}
+
+#[test]
+fn test_table_dispatch_inherent() {
+    assert_eq!(InherentShape::Circle(Circle(1.0)).area(), 3.0);
+    assert_eq!(InherentShape::Square(Square(3.0)).area(), 9.0);
+}
+
+#[test]
+fn test_table_dispatch_generic_trait() {
+    assert_eq!(ScaledShape::Circle(Circle(1.0)).scaled(2u8), 6.0);
+    assert_eq!(ScaledShape::Square(Square(2.0)).scaled(0.5f32), 2.0);
+}
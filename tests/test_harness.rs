@@ -0,0 +1,88 @@
+disponent::declare!(
+    #[disponent::configure(test_harness)]
+    pub enum Counter {
+        Simple(Simple),
+        #[disponent::test_with(Saturating { count: 0, max: 2 })]
+        Saturating(Saturating),
+        #[cfg(any())]
+        Disabled(Simple),
+    }
+
+    pub trait Count {
+        fn increment(&mut self);
+        fn count(&self) -> u32;
+    }
+);
+
+#[derive(Default)]
+pub struct Simple(u32);
+
+disponent::declare!(
+    #[disponent::configure(test_harness)]
+    pub enum HTTPCounter {
+        HTTPSimple(Simple),
+    }
+
+    pub trait HTTPCount {
+        fn requests(&self) -> u32;
+    }
+);
+
+impl HTTPCount for Simple {
+    fn requests(&self) -> u32 {
+        self.0
+    }
+}
+
+pub struct Saturating {
+    count: u32,
+    max: u32,
+}
+
+impl Count for Simple {
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+
+    fn count(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Count for Saturating {
+    fn increment(&mut self) {
+        self.count = (self.count + 1).min(self.max);
+    }
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+counter_tests! {
+    fn starts_at_zero(counter) {
+        assert_eq!(counter.count(), 0);
+    }
+
+    fn increments(mut counter) {
+        counter.increment();
+        assert_eq!(counter.count(), 1);
+    }
+
+    /// The count never decreases, even when it stops increasing
+    fn never_decreases(mut counter) {
+        let mut last = counter.count();
+        for _ in 0..5 {
+            counter.increment();
+            assert!(counter.count() >= last);
+            last = counter.count();
+        }
+    }
+}
+
+// Acronyms are kept together in the macro and test names
+http_counter_tests! {
+    fn starts_without_requests(counter) {
+        assert_eq!(counter.requests(), 0);
+    }
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "tracing")]
+
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicU64, Ordering},
+};
+
+use tracing::{
+    Event, Id, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Record},
+};
+
+disponent::declare!(
+    #[disponent::configure(tracing)]
+    pub enum FooOrBar {
+        Foo(Foo),
+        Bar(Bar),
+    }
+
+    pub trait Greet {
+        #[disponent::record(name)]
+        fn greet(&self, name: &str, secret: &str) -> String;
+
+        #[allow(async_fn_in_trait)]
+        async fn greet_async(&self) -> String;
+    }
+);
+
+pub struct Foo;
+pub struct Bar;
+
+impl Greet for Foo {
+    fn greet(&self, name: &str, _secret: &str) -> String {
+        tracing::trace!("greeting");
+        format!("Hello, {name}!")
+    }
+
+    async fn greet_async(&self) -> String {
+        tracing::trace!("greeting");
+        "Hello!".into()
+    }
+}
+
+impl Greet for Bar {
+    fn greet(&self, name: &str, _secret: &str) -> String {
+        format!("Hi, {name}!")
+    }
+
+    async fn greet_async(&self) -> String {
+        "Hi!".into()
+    }
+}
+
+/// Records new spans with their fields, and the span each event happened in.
+#[derive(Default, Clone)]
+struct Recorder {
+    next_id: Arc<AtomicU64>,
+    spans: Arc<Mutex<Vec<String>>>,
+    events: Arc<Mutex<Vec<Option<u64>>>>,
+    current: Arc<Mutex<Vec<u64>>>,
+}
+
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields(span.metadata().name().to_string());
+        span.record(&mut fields);
+        self.spans.lock().unwrap().push(fields.0);
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {
+        let current = self.current.lock().unwrap().last().copied();
+        self.events.lock().unwrap().push(current);
+    }
+
+    fn enter(&self, span: &Id) {
+        self.current.lock().unwrap().push(span.into_u64());
+    }
+
+    fn exit(&self, _: &Id) {
+        self.current.lock().unwrap().pop();
+    }
+}
+
+#[test]
+fn test_span() {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        assert_eq!(
+            FooOrBar::Foo(Foo).greet("world", "hunter2"),
+            "Hello, world!"
+        );
+        assert_eq!(FooOrBar::Bar(Bar).greet("world", "hunter2"), "Hi, world!");
+    });
+
+    assert_eq!(
+        *recorder.spans.lock().unwrap(),
+        [
+            "FooOrBar::greet variant=\"Foo\" name=\"world\"",
+            "FooOrBar::greet variant=\"Bar\" name=\"world\""
+        ]
+    );
+    assert_eq!(*recorder.events.lock().unwrap(), [Some(1)]);
+}
+
+#[test]
+fn test_async_span() {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let foo = FooOrBar::Foo(Foo);
+        let future = foo.greet_async();
+        assert!(recorder.spans.lock().unwrap().is_empty());
+
+        assert_eq!(smol::block_on(future), "Hello!");
+    });
+
+    assert_eq!(
+        *recorder.spans.lock().unwrap(),
+        ["FooOrBar::greet_async variant=\"Foo\""]
+    );
+    assert_eq!(*recorder.events.lock().unwrap(), [Some(1)]);
+    assert!(recorder.current.lock().unwrap().is_empty());
+}
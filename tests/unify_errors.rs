@@ -0,0 +1,131 @@
+use std::{error::Error, fmt, num::ParseIntError};
+
+disponent::declare!(
+    #[disponent::configure(unify_errors)]
+    pub enum Parser {
+        Int(IntParser),
+        Bool(BoolParser),
+    }
+
+    pub trait Parse {
+        type Error: Error;
+
+        fn parse(&self, input: &str) -> Result<i64, Self::Error>;
+
+        #[allow(async_fn_in_trait)]
+        async fn parse_async(&self, input: &str) -> Result<i64, Self::Error>;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub), unify_errors, dispatch = "table")]
+    pub enum InherentParser {
+        Int(IntParser),
+        r#Dec(IntParser),
+        Bool(BoolParser),
+    }
+
+    pub trait InherentParse {
+        type Error;
+
+        fn parse(&self, input: &str) -> Result<i64, Self::Error>;
+    }
+);
+
+pub struct IntParser;
+pub struct BoolParser;
+
+#[derive(Debug)]
+pub struct InvalidBool(String);
+
+impl fmt::Display for InvalidBool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid bool `{}`", self.0)
+    }
+}
+
+impl Error for InvalidBool {}
+
+impl Parse for IntParser {
+    type Error = ParseIntError;
+
+    fn parse(&self, input: &str) -> Result<i64, Self::Error> {
+        input.parse()
+    }
+
+    async fn parse_async(&self, input: &str) -> Result<i64, Self::Error> {
+        Parse::parse(self, input)
+    }
+}
+
+impl Parse for BoolParser {
+    type Error = InvalidBool;
+
+    fn parse(&self, input: &str) -> Result<i64, Self::Error> {
+        match input {
+            "true" => Ok(1),
+            "false" => Ok(0),
+            _ => Err(InvalidBool(input.to_string())),
+        }
+    }
+
+    async fn parse_async(&self, input: &str) -> Result<i64, Self::Error> {
+        Parse::parse(self, input)
+    }
+}
+
+impl InherentParse for IntParser {
+    type Error = ParseIntError;
+
+    fn parse(&self, input: &str) -> Result<i64, Self::Error> {
+        input.parse()
+    }
+}
+
+impl InherentParse for BoolParser {
+    type Error = InvalidBool;
+
+    fn parse(&self, input: &str) -> Result<i64, Self::Error> {
+        Parse::parse(self, input)
+    }
+}
+
+#[test]
+fn test_unified_errors() {
+    assert_eq!(Parser::Int(IntParser).parse("42").unwrap(), 42);
+    assert_eq!(Parser::Bool(BoolParser).parse("true").unwrap(), 1);
+
+    let error: ParserError = Parser::Int(IntParser).parse("x").unwrap_err();
+    assert!(matches!(error, ParserError::Int(_)));
+    assert_eq!(error.to_string(), "invalid digit found in string");
+
+    let error = Parser::Bool(BoolParser).parse("x").unwrap_err();
+    assert!(matches!(error, ParserError::Bool(_)));
+    assert_eq!(error.to_string(), "invalid bool `x`");
+    assert_eq!(format!("{error:?}"), "Bool(InvalidBool(\"x\"))");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_unified_errors_async() {
+    let error = smol::block_on(Parser::Bool(BoolParser).parse_async("x")).unwrap_err();
+    assert!(matches!(error, ParserError::Bool(_)));
+}
+
+#[test]
+fn test_unified_errors_from() {
+    let error: ParserError = InvalidBool("x".into()).into();
+    assert!(matches!(error, ParserError::Bool(_)));
+}
+
+#[test]
+fn test_unified_errors_inherent() {
+    let error: InherentParserError = InherentParser::Int(IntParser).parse("x").unwrap_err();
+    assert!(matches!(error, InherentParserError::Int(_)));
+
+    // Variants of the same type share an error, so only the other errors get a `From` impl
+    let error = InherentParser::Dec(IntParser).parse("x").unwrap_err();
+    assert!(format!("{error:?}").starts_with("Dec("));
+    let error: InherentParserError = InvalidBool("x".into()).into();
+    assert!(matches!(error, InherentParserError::Bool(_)));
+}
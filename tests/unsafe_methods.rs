@@ -0,0 +1,117 @@
+#![deny(unsafe_op_in_unsafe_fn)]
+
+disponent::declare!(
+    pub enum Allocator {
+        Bump(Bump),
+        Counting(Counting),
+    }
+
+    /// # Safety
+    ///
+    /// `alloc` must return a pointer to at least `size` writable bytes.
+    pub unsafe trait Alloc {
+        /// # Safety
+        ///
+        /// `size` must not be zero.
+        unsafe fn alloc(&mut self, size: usize) -> *mut u8;
+
+        fn allocated(&self) -> usize;
+    }
+);
+
+disponent::declare!(
+    #[disponent::configure(inherent(pub), dispatch = "table")]
+    pub enum InherentAllocator {
+        Bump(Bump),
+        Counting(Counting),
+    }
+
+    pub trait InherentAlloc {
+        /// # Safety
+        ///
+        /// `size` must not be zero.
+        unsafe fn alloc(&mut self, size: usize) -> *mut u8;
+    }
+);
+
+#[derive(Default)]
+pub struct Bump {
+    buf: Vec<u8>,
+}
+
+#[derive(Default)]
+pub struct Counting {
+    count: usize,
+    inner: Bump,
+}
+
+unsafe impl Alloc for Bump {
+    unsafe fn alloc(&mut self, size: usize) -> *mut u8 {
+        debug_assert_ne!(size, 0);
+        let start = self.buf.len();
+        self.buf.resize(start + size, 0);
+        // SAFETY: `start` is within the buffer, which was just resized
+        unsafe { self.buf.as_mut_ptr().add(start) }
+    }
+
+    fn allocated(&self) -> usize {
+        self.buf.len()
+    }
+}
+
+unsafe impl Alloc for Counting {
+    unsafe fn alloc(&mut self, size: usize) -> *mut u8 {
+        self.count += 1;
+        // SAFETY: the caller guarantees that `size` is not zero
+        unsafe { Alloc::alloc(&mut self.inner, size) }
+    }
+
+    fn allocated(&self) -> usize {
+        self.inner.allocated()
+    }
+}
+
+impl InherentAlloc for Bump {
+    unsafe fn alloc(&mut self, size: usize) -> *mut u8 {
+        // SAFETY: the caller guarantees that `size` is not zero
+        unsafe { Alloc::alloc(self, size) }
+    }
+}
+
+impl InherentAlloc for Counting {
+    unsafe fn alloc(&mut self, size: usize) -> *mut u8 {
+        // SAFETY: the caller guarantees that `size` is not zero
+        unsafe { Alloc::alloc(self, size) }
+    }
+}
+
+fn alloc_zeroed<A: Alloc>(alloc: &mut A, size: usize) -> *mut u8 {
+    assert_ne!(size, 0);
+    // SAFETY: `size` is not zero
+    unsafe { alloc.alloc(size) }
+}
+
+#[test]
+fn unsafe_trait_is_implemented() {
+    let mut alloc = Allocator::Counting(Counting::default());
+    assert!(!alloc_zeroed(&mut alloc, 4).is_null());
+    assert_eq!(alloc.allocated(), 4);
+
+    let Allocator::Counting(counting) = alloc else {
+        unreachable!()
+    };
+    assert_eq!(counting.count, 1);
+}
+
+#[test]
+fn unsafe_inherent_methods_are_forwarded() {
+    let mut alloc = InherentAllocator::Bump(Bump::default());
+    // SAFETY: `size` is not zero
+    let ptr = unsafe { alloc.alloc(8) };
+    assert!(!ptr.is_null());
+
+    let InherentAllocator::Bump(bump) = alloc else {
+        unreachable!()
+    };
+    assert_eq!(bump.allocated(), 8);
+}